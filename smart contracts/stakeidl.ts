@@ -2,56 +2,287 @@ export const IDL = {
   version: "0.1.0",
   name: "credential_verifier",
   instructions: [
+    {
+      name: "initializeConfig",
+      accounts: [
+        { name: "config", isMut: true, isSigner: false },
+        { name: "insurancePool", isMut: true, isSigner: false },
+        { name: "admin", isMut: true, isSigner: true },
+        { name: "program", isMut: false, isSigner: false },
+        { name: "programData", isMut: false, isSigner: false },
+        { name: "systemProgram", isMut: false, isSigner: false },
+      ],
+      args: [
+        { name: "treasuryShareBps", type: "u16" },
+        { name: "nonVoterSlashBps", type: "u16" },
+        { name: "appealBond", type: "u64" },
+        { name: "roundLimits", type: { defined: "RoundLimits" } },
+      ],
+    },
+    {
+      name: "updateSlashingConfig",
+      accounts: [
+        { name: "config", isMut: true, isSigner: false },
+        { name: "admin", isMut: false, isSigner: true },
+      ],
+      args: [
+        { name: "treasuryShareBps", type: "u16" },
+        { name: "nonVoterSlashBps", type: "u16" },
+      ],
+    },
+    {
+      name: "setEligibilityRule",
+      accounts: [
+        { name: "config", isMut: true, isSigner: false },
+        { name: "admin", isMut: false, isSigner: true },
+      ],
+      args: [{ name: "eligibility", type: { defined: "EligibilityRule" } }],
+    },
+    {
+      name: "setRequirePool",
+      accounts: [
+        { name: "config", isMut: true, isSigner: false },
+        { name: "admin", isMut: false, isSigner: true },
+      ],
+      args: [{ name: "requirePool", type: "bool" }],
+    },
+    {
+      name: "setAppealBond",
+      accounts: [
+        { name: "config", isMut: true, isSigner: false },
+        { name: "admin", isMut: false, isSigner: true },
+      ],
+      args: [{ name: "appealBond", type: "u64" }],
+    },
+    {
+      name: "setRoundLimits",
+      accounts: [
+        { name: "config", isMut: true, isSigner: false },
+        { name: "admin", isMut: false, isSigner: true },
+      ],
+      args: [{ name: "roundLimits", type: { defined: "RoundLimits" } }],
+    },
+    {
+      name: "addToAllowlist",
+      accounts: [
+        { name: "config", isMut: false, isSigner: false },
+        { name: "allowlistEntry", isMut: true, isSigner: false },
+        { name: "admin", isMut: true, isSigner: true },
+        { name: "systemProgram", isMut: false, isSigner: false },
+      ],
+      args: [{ name: "verifier", type: "publicKey" }],
+    },
+    {
+      name: "removeFromAllowlist",
+      accounts: [
+        { name: "config", isMut: false, isSigner: false },
+        { name: "allowlistEntry", isMut: true, isSigner: false },
+        { name: "admin", isMut: true, isSigner: true },
+      ],
+      args: [],
+    },
+    {
+      name: "addPoolMember",
+      accounts: [
+        { name: "config", isMut: false, isSigner: false },
+        { name: "poolMembership", isMut: true, isSigner: false },
+        { name: "admin", isMut: true, isSigner: true },
+        { name: "systemProgram", isMut: false, isSigner: false },
+      ],
+      args: [
+        { name: "credentialType", type: { defined: "CredentialType" } },
+        { name: "tag", type: "string" },
+        { name: "member", type: "publicKey" },
+      ],
+    },
+    {
+      name: "removePoolMember",
+      accounts: [
+        { name: "config", isMut: false, isSigner: false },
+        { name: "poolMembership", isMut: true, isSigner: false },
+        { name: "admin", isMut: true, isSigner: true },
+      ],
+      args: [],
+    },
+    {
+      name: "registerVerifier",
+      accounts: [
+        { name: "reputation", isMut: true, isSigner: false },
+        { name: "authority", isMut: true, isSigner: true },
+        { name: "systemProgram", isMut: false, isSigner: false },
+      ],
+      args: [],
+    },
     {
       name: "initializeCredential",
       accounts: [
         { name: "credential", isMut: true, isSigner: false },
+        { name: "vault", isMut: true, isSigner: false },
+        { name: "targetCredential", isMut: false, isSigner: false },
+        { name: "config", isMut: true, isSigner: false },
         { name: "authority", isMut: true, isSigner: true },
         { name: "systemProgram", isMut: false, isSigner: false },
       ],
       args: [
-        { name: "credentialId", type: "string" },
-        { name: "stakeAmount", type: "u64" },
+        { name: "credentialType", type: { defined: "CredentialType" } },
+        { name: "params", type: { defined: "RoundParams" } },
+      ],
+    },
+    {
+      name: "openAppeal",
+      accounts: [
+        { name: "original", isMut: true, isSigner: false },
+        { name: "appeal", isMut: true, isSigner: false },
+        { name: "vault", isMut: true, isSigner: false },
+        { name: "config", isMut: true, isSigner: false },
+        { name: "appellant", isMut: true, isSigner: true },
+        { name: "systemProgram", isMut: false, isSigner: false },
+      ],
+      args: [{ name: "params", type: { defined: "RoundParams" } }],
+    },
+    {
+      name: "resolveAppeal",
+      accounts: [
+        { name: "original", isMut: true, isSigner: false },
+        { name: "appeal", isMut: true, isSigner: false },
+        { name: "appealVault", isMut: true, isSigner: false },
+        { name: "appellant", isMut: true, isSigner: false },
+        { name: "insurancePool", isMut: true, isSigner: false },
+        { name: "systemProgram", isMut: false, isSigner: false },
       ],
+      args: [],
     },
     {
       name: "stakeForCredential",
       accounts: [
         { name: "credential", isMut: true, isSigner: false },
+        { name: "vault", isMut: true, isSigner: false },
         { name: "verifier", isMut: true, isSigner: false },
+        {
+          name: "allowlistEntry",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "poolMembership",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        { name: "reputation", isMut: true, isSigner: false },
         { name: "authority", isMut: true, isSigner: true },
         { name: "systemProgram", isMut: false, isSigner: false },
       ],
-      args: [],
+      args: [{ name: "amount", type: "u64" }],
     },
     {
-      name: "makeDecision",
+      name: "commitVote",
       accounts: [
         { name: "credential", isMut: true, isSigner: false },
         { name: "verifier", isMut: true, isSigner: false },
         { name: "authority", isMut: false, isSigner: true },
         { name: "systemProgram", isMut: false, isSigner: false },
       ],
-      args: [{ name: "isAuthentic", type: "bool" }],
+      args: [{ name: "commitment", type: { array: ["u8", 32] } }],
+    },
+    {
+      name: "revealVote",
+      accounts: [
+        { name: "credential", isMut: true, isSigner: false },
+        { name: "verifier", isMut: true, isSigner: false },
+        { name: "authority", isMut: false, isSigner: true },
+        { name: "systemProgram", isMut: false, isSigner: false },
+      ],
+      args: [
+        { name: "isAuthentic", type: "bool" },
+        { name: "salt", type: { array: ["u8", 32] } },
+      ],
+    },
+    {
+      name: "finalizeAfterDeadline",
+      accounts: [{ name: "credential", isMut: true, isSigner: false }],
+      args: [],
     },
     {
       name: "claimReward",
       accounts: [
         { name: "credential", isMut: true, isSigner: false },
+        { name: "vault", isMut: true, isSigner: false },
         { name: "verifier", isMut: true, isSigner: false },
+        { name: "reputation", isMut: true, isSigner: false },
         { name: "authority", isMut: true, isSigner: true },
         { name: "systemProgram", isMut: false, isSigner: false },
       ],
+      args: [{ name: "closeVerifier", type: "bool" }],
+    },
+    {
+      name: "slashVerifier",
+      accounts: [
+        { name: "credential", isMut: true, isSigner: false },
+        { name: "vault", isMut: true, isSigner: false },
+        { name: "verifier", isMut: true, isSigner: false },
+        { name: "authority", isMut: true, isSigner: false },
+        { name: "reputation", isMut: true, isSigner: false },
+        { name: "insurancePool", isMut: true, isSigner: false },
+        { name: "systemProgram", isMut: false, isSigner: false },
+      ],
+      args: [],
+    },
+    {
+      name: "settleVerification",
+      accounts: [
+        { name: "credential", isMut: true, isSigner: false },
+        { name: "targetCredential", isMut: true, isSigner: false },
+        { name: "targetProfile", isMut: true, isSigner: false },
+        { name: "tabluConfig", isMut: false, isSigner: false },
+        { name: "settlementAuthority", isMut: false, isSigner: false },
+        { name: "tabluProgram", isMut: false, isSigner: false },
+      ],
+      args: [],
+    },
+    {
+      name: "cancelCredential",
+      accounts: [{ name: "credential", isMut: true, isSigner: false }],
+      args: [],
+    },
+    {
+      name: "supersedeRound",
+      accounts: [
+        { name: "credential", isMut: true, isSigner: false },
+        { name: "targetCredential", isMut: false, isSigner: false },
+      ],
       args: [],
     },
     {
       name: "refundExpiredStakes",
       accounts: [
         { name: "credential", isMut: true, isSigner: false },
+        { name: "vault", isMut: true, isSigner: false },
         { name: "verifier", isMut: true, isSigner: false },
         { name: "authority", isMut: true, isSigner: true },
         { name: "systemProgram", isMut: false, isSigner: false },
       ],
+      args: [{ name: "closeVerifier", type: "bool" }],
+    },
+    {
+      name: "closeCredential",
+      accounts: [
+        { name: "credential", isMut: true, isSigner: false },
+        { name: "vault", isMut: true, isSigner: false },
+        { name: "creator", isMut: true, isSigner: false },
+        { name: "insurancePool", isMut: true, isSigner: false },
+        { name: "systemProgram", isMut: false, isSigner: false },
+      ],
+      args: [],
+    },
+    {
+      name: "closeVerifier",
+      accounts: [
+        { name: "verifier", isMut: true, isSigner: false },
+        { name: "credential", isMut: false, isSigner: false },
+        { name: "authority", isMut: true, isSigner: true },
+      ],
       args: [],
     },
   ],
@@ -61,14 +292,66 @@ export const IDL = {
       type: {
         kind: "struct",
         fields: [
-          { name: "credentialId", type: "string" },
-          { name: "stakeAmount", type: "u64" },
-          { name: "verifications", type: "u32" },
+          { name: "roundId", type: "u64" },
+          { name: "votingMode", type: { defined: "VotingMode" } },
+          { name: "minStake", type: "u64" },
+          { name: "maxStake", type: "u64" },
+          { name: "voteCount", type: "u32" },
           { name: "authenticVotes", type: "u32" },
+          { name: "revealedWeight", type: "u64" },
+          { name: "authenticWeight", type: "u64" },
+          { name: "revealedStake", type: "u64" },
+          { name: "authenticStake", type: "u64" },
+          { name: "revealedForfeit", type: "u64" },
+          { name: "authenticForfeit", type: "u64" },
+          { name: "committedForfeit", type: "u64" },
+          { name: "abstainForfeit", type: "u64" },
+          { name: "committedAbstainForfeit", type: "u64" },
           { name: "totalStaked", type: "u64" },
           { name: "isFinalized", type: "bool" },
           { name: "createdAt", type: "i64" },
-          { name: "verifierCount", type: "u32" },
+          { name: "stakerCount", type: "u32" },
+          { name: "target", type: "publicKey" },
+          { name: "credentialType", type: { defined: "CredentialType" } },
+          { name: "targetRevision", type: "u32" },
+          { name: "targetOwner", type: "publicKey" },
+          { name: "eligibility", type: { defined: "EligibilityRule" } },
+          { name: "minReputation", type: "u32" },
+          { name: "requirePool", type: "bool" },
+          { name: "poolTag", type: "string" },
+          { name: "isSettled", type: "bool" },
+          { name: "isSuperseded", type: "bool" },
+          { name: "isCancelled", type: "bool" },
+          { name: "refundedCount", type: "u32" },
+          { name: "remainingPool", type: "u64" },
+          { name: "claimedCount", type: "u32" },
+          { name: "quorum", type: "u32" },
+          { name: "majorityBps", type: "u16" },
+          { name: "tiePolicy", type: { defined: "TiePolicy" } },
+          { name: "outcome", type: { defined: "Outcome" } },
+          { name: "stakingWindow", type: "i64" },
+          { name: "votingWindow", type: "i64" },
+          { name: "claimWindow", type: "i64" },
+          { name: "stakingDeadline", type: "i64" },
+          { name: "votingDeadline", type: "i64" },
+          { name: "revealWindow", type: "i64" },
+          { name: "revealDeadline", type: "i64" },
+          { name: "commitCount", type: "u32" },
+          { name: "claimDeadline", type: "i64" },
+          { name: "appealWindow", type: "i64" },
+          { name: "appealDeadline", type: "i64" },
+          { name: "appeal", type: "publicKey" },
+          { name: "appealResolved", type: "bool" },
+          { name: "overturned", type: "bool" },
+          { name: "appealOf", type: "publicKey" },
+          { name: "appellant", type: "publicKey" },
+          { name: "appealBond", type: "u64" },
+          { name: "creator", type: "publicKey" },
+          { name: "vaultBump", type: "u8" },
+          { name: "treasuryShareBps", type: "u16" },
+          { name: "nonVoterSlashBps", type: "u16" },
+          { name: "rewardPool", type: "u64" },
+          { name: "slashedCount", type: "u32" },
         ],
       },
     },
@@ -78,14 +361,222 @@ export const IDL = {
         kind: "struct",
         fields: [
           { name: "credential", type: "publicKey" },
+          { name: "roundId", type: "u64" },
           { name: "authority", type: "publicKey" },
+          { name: "stake", type: "u64" },
+          { name: "weight", type: "u64" },
           { name: "votedAuthentic", type: "bool" },
           { name: "hasVoted", type: "bool" },
           { name: "hasClaimed", type: "bool" },
+          { name: "commitment", type: { array: ["u8", 32] } },
+          { name: "hasCommitted", type: "bool" },
+        ],
+      },
+    },
+    {
+      name: "ProtocolConfig",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "admin", type: "publicKey" },
+          { name: "treasuryShareBps", type: "u16" },
+          { name: "nonVoterSlashBps", type: "u16" },
+          { name: "eligibility", type: { defined: "EligibilityRule" } },
+          { name: "requirePool", type: "bool" },
+          { name: "appealBond", type: "u64" },
+          { name: "roundLimits", type: { defined: "RoundLimits" } },
+          { name: "roundsOpened", type: "u64" },
+          { name: "bump", type: "u8" },
+        ],
+      },
+    },
+    {
+      name: "AllowlistEntry",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "verifier", type: "publicKey" },
+          { name: "bump", type: "u8" },
+        ],
+      },
+    },
+    {
+      name: "PoolMembership",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "credentialType", type: { defined: "CredentialType" } },
+          { name: "tag", type: "string" },
+          { name: "member", type: "publicKey" },
+          { name: "bump", type: "u8" },
+        ],
+      },
+    },
+    {
+      name: "VerifierReputation",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "authority", type: "publicKey" },
+          { name: "registeredAt", type: "i64" },
+          { name: "votes", type: "u32" },
+          { name: "majorityHits", type: "u32" },
+          { name: "slashes", type: "u32" },
+          { name: "lifetimeEarnings", type: "u64" },
+          { name: "bump", type: "u8" },
+        ],
+      },
+    },
+    {
+      name: "InsurancePool",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "totalReceived", type: "u64" },
+          { name: "bump", type: "u8" },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "CredentialType",
+      type: {
+        kind: "enum",
+        variants: [
+          { name: "Degree" },
+          { name: "Project" },
+          { name: "Skill" },
+          { name: "Employment" },
+          { name: "Certificate" },
+        ],
+      },
+    },
+    {
+      name: "RoundLimits",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "minQuorum", type: "u32" },
+          { name: "minStake", type: "u64" },
+          { name: "minWindow", type: "i64" },
+          { name: "maxWindow", type: "i64" },
+        ],
+      },
+    },
+    {
+      name: "RoundParams",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "votingMode", type: { defined: "VotingMode" } },
+          { name: "minStake", type: "u64" },
+          { name: "maxStake", type: "u64" },
+          { name: "quorum", type: "u32" },
+          { name: "majorityBps", type: "u16" },
+          { name: "tiePolicy", type: { defined: "TiePolicy" } },
+          { name: "stakingWindow", type: "i64" },
+          { name: "votingWindow", type: "i64" },
+          { name: "revealWindow", type: "i64" },
+          { name: "claimWindow", type: "i64" },
+          { name: "minReputation", type: "u32" },
+          { name: "poolTag", type: "string" },
+          { name: "appealWindow", type: "i64" },
+        ],
+      },
+    },
+    {
+      name: "EligibilityRule",
+      type: {
+        kind: "enum",
+        variants: [
+          { name: "Open" },
+          { name: "Allowlist" },
+          { name: "MinAccountAge", fields: [{ name: "seconds", type: "i64" }] },
+        ],
+      },
+    },
+    {
+      name: "VotingMode",
+      type: {
+        kind: "enum",
+        variants: [
+          { name: "Equal" },
+          { name: "StakeWeighted" },
+          { name: "ReputationWeighted" },
+        ],
+      },
+    },
+    {
+      name: "TiePolicy",
+      type: {
+        kind: "enum",
+        variants: [
+          { name: "RefundAll" },
+          { name: "Extend" },
+          { name: "Escalate" },
+        ],
+      },
+    },
+    {
+      name: "Outcome",
+      type: {
+        kind: "enum",
+        variants: [
+          { name: "Undecided" },
+          { name: "Authentic" },
+          { name: "NotAuthentic" },
+          { name: "Tie" },
         ],
       },
     },
   ],
+  events: [
+    {
+      name: "VerificationEscalated",
+      fields: [
+        { name: "credential", type: "publicKey", index: false },
+        { name: "target", type: "publicKey", index: false },
+        { name: "authenticVotes", type: "u32", index: false },
+        { name: "votes", type: "u32", index: false },
+      ],
+    },
+    {
+      name: "SlashEvent",
+      fields: [
+        { name: "credential", type: "publicKey", index: false },
+        { name: "verifier", type: "publicKey", index: false },
+        { name: "voted", type: "bool", index: false },
+        { name: "amount", type: "u64", index: false },
+        { name: "toTreasury", type: "u64", index: false },
+        { name: "toWinners", type: "u64", index: false },
+      ],
+    },
+    {
+      name: "RoundSuperseded",
+      fields: [
+        { name: "credential", type: "publicKey", index: false },
+        { name: "target", type: "publicKey", index: false },
+      ],
+    },
+    {
+      name: "AppealOpened",
+      fields: [
+        { name: "original", type: "publicKey", index: false },
+        { name: "appeal", type: "publicKey", index: false },
+        { name: "appellant", type: "publicKey", index: false },
+        { name: "bond", type: "u64", index: false },
+      ],
+    },
+    {
+      name: "AppealResolved",
+      fields: [
+        { name: "original", type: "publicKey", index: false },
+        { name: "appeal", type: "publicKey", index: false },
+        { name: "overturned", type: "bool", index: false },
+      ],
+    },
+  ],
   errors: [
     {
       code: 6000,
@@ -122,5 +613,222 @@ export const IDL = {
       name: "MaxVerifiersReached",
       msg: "Maximum number of verifiers reached",
     },
+    {
+      code: 6011,
+      name: "AlreadySettled",
+      msg: "Verification outcome has already been settled",
+    },
+    {
+      code: 6012,
+      name: "NotInMajority",
+      msg: "Verifier did not vote with the majority",
+    },
+    {
+      code: 6013,
+      name: "DuplicateVoter",
+      msg: "Verifier was passed more than once",
+    },
+    {
+      code: 6014,
+      name: "IncompleteMajority",
+      msg: "Every majority verifier must be provided",
+    },
+    {
+      code: 6015,
+      name: "TargetMismatch",
+      msg: "Account does not match the credential being verified",
+    },
+    {
+      code: 6016,
+      name: "AllRewardsClaimed",
+      msg: "Every majority verifier has already been paid",
+    },
+    {
+      code: 6017,
+      name: "InsufficientPool",
+      msg: "Reward pool cannot cover this payout",
+    },
+    { code: 6018, name: "MathOverflow", msg: "Arithmetic overflow" },
+    {
+      code: 6019,
+      name: "InvalidQuorum",
+      msg: "Quorum must be between the protocol minimum and the maximum number of verifiers",
+    },
+    {
+      code: 6020,
+      name: "InvalidMajority",
+      msg: "Majority must be above 50% and at most 100%",
+    },
+    { code: 6021, name: "NoMajority", msg: "Verification ended in a tie" },
+    {
+      code: 6022,
+      name: "InvalidWindow",
+      msg: "Round windows must lie within the protocol's limits",
+    },
+    {
+      code: 6023,
+      name: "VotingNotOpen",
+      msg: "Voting opens once every staking slot is filled",
+    },
+    { code: 6024, name: "VotingPeriodEnded", msg: "Voting period has ended" },
+    {
+      code: 6025,
+      name: "VotingStillOpen",
+      msg: "Voting period has not ended yet",
+    },
+    { code: 6026, name: "ClaimPeriodEnded", msg: "Claim period has ended" },
+    {
+      code: 6027,
+      name: "InvalidSlashRate",
+      msg: "Slashing rates must be at most 100%",
+    },
+    {
+      code: 6028,
+      name: "Unauthorized",
+      msg: "Only the config admin can perform this action",
+    },
+    {
+      code: 6029,
+      name: "NotSlashable",
+      msg: "Verifier voted with the winning side",
+    },
+    {
+      code: 6030,
+      name: "NotCommitted",
+      msg: "Verifier has not committed a vote",
+    },
+    {
+      code: 6031,
+      name: "RevealNotOpen",
+      msg: "Votes can be revealed once every staker has committed or the commit phase has ended",
+    },
+    { code: 6032, name: "RevealPeriodEnded", msg: "Reveal period has ended" },
+    {
+      code: 6033,
+      name: "CommitmentMismatch",
+      msg: "Revealed vote does not match the commitment",
+    },
+    {
+      code: 6034,
+      name: "TargetNotPending",
+      msg: "Target credential is not pending verification",
+    },
+    {
+      code: 6035,
+      name: "TargetChanged",
+      msg: "Target credential was edited after the round opened",
+    },
+    {
+      code: 6036,
+      name: "SelfVerification",
+      msg: "Credential owners cannot verify their own credentials",
+    },
+    {
+      code: 6037,
+      name: "NotEligible",
+      msg: "Wallet does not meet the staking eligibility rule",
+    },
+    {
+      code: 6038,
+      name: "ReputationTooLow",
+      msg: "Verifier reputation is below the credential's minimum",
+    },
+    {
+      code: 6039,
+      name: "InvalidStakeRange",
+      msg: "Stake range must meet the protocol minimum, be ordered, and be fixed for equal voting",
+    },
+    {
+      code: 6040,
+      name: "InvalidStakeAmount",
+      msg: "Stake is outside the credential's allowed range",
+    },
+    { code: 6041, name: "PoolTagTooLong", msg: "Pool tag is too long" },
+    { code: 6042, name: "AppealWindowClosed", msg: "Appeal window has closed" },
+    {
+      code: 6043,
+      name: "NotAppealable",
+      msg: "Appeal rounds cannot be appealed again",
+    },
+    {
+      code: 6044,
+      name: "AppealTooSmall",
+      msg: "Appeal round must have a larger quorum than the original",
+    },
+    {
+      code: 6045,
+      name: "AppealAlreadyResolved",
+      msg: "Appeal has already been resolved",
+    },
+    {
+      code: 6046,
+      name: "AppealUnresolved",
+      msg: "Round's appeal has not been resolved yet",
+    },
+    {
+      code: 6047,
+      name: "PayoutsLocked",
+      msg: "Payouts are locked until the appeal window passes or the appeal is resolved",
+    },
+    {
+      code: 6048,
+      name: "NotClosable",
+      msg: "Every verifier must be paid, slashed or refunded before closing",
+    },
+    {
+      code: 6049,
+      name: "VerifierStillNeeded",
+      msg: "Verifier account is still needed to settle the round",
+    },
+    {
+      code: 6050,
+      name: "VerifierNotTerminal",
+      msg: "Verifier can still claim, be slashed or be refunded",
+    },
+    {
+      code: 6051,
+      name: "Cancelled",
+      msg: "Credential verification has been cancelled",
+    },
+    {
+      code: 6052,
+      name: "NotCancelled",
+      msg: "Stakes can only be refunded from a cancelled round",
+    },
+    {
+      code: 6053,
+      name: "InvariantViolation",
+      msg: "Credential accounting invariant violated",
+    },
+    {
+      code: 6054,
+      name: "StaleVerifier",
+      msg: "Verifier belongs to an earlier round of this credential",
+    },
+    {
+      code: 6055,
+      name: "RoundSuperseded",
+      msg: "Round's outcome was superseded and will not be settled",
+    },
+    {
+      code: 6056,
+      name: "TargetUnchanged",
+      msg: "Target credential has not changed since the round opened",
+    },
+    {
+      code: 6057,
+      name: "AppealTooLenient",
+      msg: "Appeal round must be at least as strict as the original",
+    },
+    {
+      code: 6058,
+      name: "NotInPool",
+      msg: "Wallet is not a member of the credential's verifier pool",
+    },
+    {
+      code: 6059,
+      name: "InvalidRoundLimits",
+      msg: "Round limits must have a valid quorum, a positive stake and an ordered window range",
+    },
   ],
 };
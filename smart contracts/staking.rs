@@ -1,55 +1,333 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use tablu::cpi::accounts::{
+    UpdateCertificateVerification, UpdateDegreeVerification, UpdateEmploymentVerification,
+    UpdateProjectVerification, UpdateSkillVerification,
+};
+use tablu::program::Tablu;
+use tablu::{CredentialType, VerificationStatus};
 
 declare_id!("HEqjbSEneAypSr9p8RhrjuK4wz98jfDZykmEDyjBcX4m");
 
+/// Every majority voter is recorded on the tablu credential, so the quorum is
+/// bounded by how many verifiers it can hold.
+pub const MAX_QUORUM: u32 = tablu::MAX_CREDENTIAL_VERIFIERS as u32;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_REPUTATION_MULTIPLIER: u64 = 4;
+/// Pool tags are used directly as a PDA seed, which caps them at 32 bytes.
+pub const MAX_POOL_TAG_LEN: usize = 32;
+
 #[program]
 pub mod credential_verifier {
     use super::*;
 
+    /// Creates the global slashing config and the insurance pool that receives
+    /// the protocol's share of slashed stakes.
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        treasury_share_bps: u16,
+        non_voter_slash_bps: u16,
+        appeal_bond: u64,
+        round_limits: RoundLimits,
+    ) -> Result<()> {
+        validate_slash_rates(treasury_share_bps, non_voter_slash_bps)?;
+        round_limits.validate()?;
+
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.admin.key();
+        config.treasury_share_bps = treasury_share_bps;
+        config.non_voter_slash_bps = non_voter_slash_bps;
+        config.eligibility = EligibilityRule::Open;
+        config.appeal_bond = appeal_bond;
+        config.round_limits = round_limits;
+        config.bump = ctx.bumps.config;
+
+        let insurance_pool = &mut ctx.accounts.insurance_pool;
+        insurance_pool.total_received = 0;
+        insurance_pool.bump = ctx.bumps.insurance_pool;
+        Ok(())
+    }
+
+    /// Changes the slashing rates used by credentials created from now on.
+    pub fn update_slashing_config(
+        ctx: Context<UpdateSlashingConfig>,
+        treasury_share_bps: u16,
+        non_voter_slash_bps: u16,
+    ) -> Result<()> {
+        validate_slash_rates(treasury_share_bps, non_voter_slash_bps)?;
+
+        let config = &mut ctx.accounts.config;
+        config.treasury_share_bps = treasury_share_bps;
+        config.non_voter_slash_bps = non_voter_slash_bps;
+        Ok(())
+    }
+
+    /// Changes who may stake on credentials created from now on.
+    pub fn set_eligibility_rule(
+        ctx: Context<SetEligibilityRule>,
+        eligibility: EligibilityRule,
+    ) -> Result<()> {
+        ctx.accounts.config.eligibility = eligibility;
+        Ok(())
+    }
+
+    /// Turns the verifier pool requirement on or off for rounds opened from
+    /// now on, independently of the eligibility rule.
+    pub fn set_require_pool(ctx: Context<SetRequirePool>, require_pool: bool) -> Result<()> {
+        ctx.accounts.config.require_pool = require_pool;
+        Ok(())
+    }
+
+    pub fn set_appeal_bond(ctx: Context<SetAppealBond>, appeal_bond: u64) -> Result<()> {
+        ctx.accounts.config.appeal_bond = appeal_bond;
+        Ok(())
+    }
+
+    /// Changes the bounds on round parameters for rounds opened from now on.
+    pub fn set_round_limits(ctx: Context<SetRoundLimits>, round_limits: RoundLimits) -> Result<()> {
+        round_limits.validate()?;
+        ctx.accounts.config.round_limits = round_limits;
+        Ok(())
+    }
+
+    pub fn add_to_allowlist(ctx: Context<AddToAllowlist>, verifier: Pubkey) -> Result<()> {
+        let entry = &mut ctx.accounts.allowlist_entry;
+        entry.verifier = verifier;
+        entry.bump = ctx.bumps.allowlist_entry;
+        Ok(())
+    }
+
+    pub fn remove_from_allowlist(_ctx: Context<RemoveFromAllowlist>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Admits `member` to the verifier pool for `credential_type`, narrowed to
+    /// a college, company or similar by a non-empty `tag`.
+    pub fn add_pool_member(
+        ctx: Context<AddPoolMember>,
+        credential_type: CredentialType,
+        tag: String,
+        member: Pubkey,
+    ) -> Result<()> {
+        require!(tag.len() <= MAX_POOL_TAG_LEN, VerifierError::PoolTagTooLong);
+
+        let membership = &mut ctx.accounts.pool_membership;
+        membership.credential_type = credential_type;
+        membership.tag = tag;
+        membership.member = member;
+        membership.bump = ctx.bumps.pool_membership;
+        Ok(())
+    }
+
+    pub fn remove_pool_member(_ctx: Context<RemovePoolMember>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Creates the caller's reputation record ahead of its first stake, so it
+    /// can age towards `EligibilityRule::MinAccountAge`.
+    pub fn register_verifier(ctx: Context<RegisterVerifier>) -> Result<()> {
+        let reputation = &mut ctx.accounts.reputation;
+        reputation.init_if_new(
+            ctx.accounts.authority.key(),
+            Clock::get()?.unix_timestamp,
+            ctx.bumps.reputation,
+        );
+        Ok(())
+    }
+
     pub fn initialize_credential(
         ctx: Context<InitializeCredential>,
-        credential_id: String,
-        stake_amount: u64,
+        credential_type: CredentialType,
+        params: RoundParams,
     ) -> Result<()> {
+        let target =
+            tablu::load_credential(&ctx.accounts.target_credential, credential_type.clone())?;
+        require!(
+            *target.status() == VerificationStatus::Pending,
+            VerifierError::TargetNotPending
+        );
+
         let credential = &mut ctx.accounts.credential;
         let clock = Clock::get()?;
 
-        credential.credential_id = credential_id;
-        credential.stake_amount = stake_amount;
-        credential.verifications = 0;
-        credential.authentic_votes = 0;
-        credential.total_staked = 0;
-        credential.is_finalized = false;
-        credential.created_at = clock.unix_timestamp;
+        credential.target = ctx.accounts.target_credential.key();
+        credential.credential_type = credential_type;
+        credential.target_revision = target.revision();
+        credential.target_owner = target.user_address();
+        credential.appeal_window = params.appeal_window;
+        credential.creator = ctx.accounts.authority.key();
+        credential.vault_bump = ctx.bumps.vault;
+        credential.open_round(params, &mut ctx.accounts.config, clock.unix_timestamp)?;
+
+        // The vault keeps its rent-exempt minimum until the credential closes.
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            Rent::get()?.minimum_balance(0),
+        )?;
+        credential.assert_invariants()?;
         Ok(())
     }
 
-    pub fn stake_for_credential(ctx: Context<StakeForCredential>) -> Result<()> {
-        let credential = &mut ctx.accounts.credential;
-        let verifier = &mut ctx.accounts.verifier;
-        let clock = Clock::get()?;
+    /// Challenges a decisive outcome within its appeal window. The appellant
+    /// posts the configured bond and a second, larger round opens on the same
+    /// target; a decisive opposite result replaces the first round's.
+    pub fn open_appeal(ctx: Context<OpenAppeal>, params: RoundParams) -> Result<()> {
+        let original = &mut ctx.accounts.original;
+        let now = Clock::get()?.unix_timestamp;
 
-        // Check if within 5-day window
-        let time_elapsed = clock.unix_timestamp - credential.created_at;
-        let five_days_in_secs = 5 * 24 * 60 * 60;
+        require!(original.is_finalized, VerifierError::NotFinalized);
+        require!(!original.is_superseded, VerifierError::RoundSuperseded);
         require!(
-            time_elapsed <= five_days_in_secs,
-            VerifierError::StakingPeriodEnded
+            original.appeal_window > 0 && now <= original.appeal_deadline,
+            VerifierError::AppealWindowClosed
         );
+        require!(original.outcome != Outcome::Tie, VerifierError::NoMajority);
         require!(
-            credential.verifications < 10,
-            VerifierError::MaxVerifiersReached
+            params.quorum > original.quorum,
+            VerifierError::AppealTooSmall
         );
+        // The appellant picks the appeal's terms, so they may only be stricter
+        // than the original's; cheap sybil stakes cannot outvote the first round.
+        require!(
+            params.voting_mode == original.voting_mode
+                && params.min_stake >= original.min_stake
+                && params.majority_bps >= original.majority_bps
+                && params.min_reputation >= original.min_reputation
+                && params.pool_tag == original.pool_tag,
+            VerifierError::AppealTooLenient
+        );
+
+        let bond = ctx.accounts.config.appeal_bond;
+        let appeal = &mut ctx.accounts.appeal;
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.appellant.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            bond + Rent::get()?.minimum_balance(0),
+        )?;
+
+        appeal.target = original.target;
+        appeal.credential_type = original.credential_type.clone();
+        appeal.target_revision = original.target_revision;
+        appeal.target_owner = original.target_owner;
+        appeal.appeal_of = original.key();
+        appeal.appellant = ctx.accounts.appellant.key();
+        appeal.appeal_bond = bond;
+        appeal.appeal_window = 0;
+        appeal.creator = ctx.accounts.appellant.key();
+        appeal.vault_bump = ctx.bumps.vault;
+        appeal.open_round(params, &mut ctx.accounts.config, now)?;
+        original.appeal = appeal.key();
 
-        require!(!credential.is_finalized, VerifierError::AlreadyFinalized);
+        emit!(AppealOpened {
+            original: original.key(),
+            appeal: appeal.key(),
+            appellant: appeal.appellant,
+            bond,
+        });
+        original.assert_invariants()?;
+        appeal.assert_invariants()?;
+        Ok(())
+    }
+
+    /// Applies a finalized or cancelled appeal to the round it challenged. A decisive
+    /// opposite outcome overturns the first round, making its majority
+    /// slashable, returns the bond, and is settled through the appeal round.
+    /// Otherwise the bond goes to the insurance pool and the first round's
+    /// outcome stands and is settled through it.
+    pub fn resolve_appeal(ctx: Context<ResolveAppeal>) -> Result<()> {
+        let original = &mut ctx.accounts.original;
+        let appeal = &mut ctx.accounts.appeal;
+        let now = Clock::get()?.unix_timestamp;
+
+        // A cancelled appeal leaves the original outcome standing.
         require!(
-            verifier.credential == Pubkey::default(),
-            VerifierError::AlreadyStaked
+            appeal.is_finalized || appeal.is_cancelled,
+            VerifierError::NotFinalized
+        );
+        require!(
+            !original.appeal_resolved,
+            VerifierError::AppealAlreadyResolved
         );
 
+        let overturned = appeal.is_finalized
+            && appeal.outcome != Outcome::Tie
+            && appeal.outcome != original.outcome;
+        let bond_recipient = if overturned {
+            original.overturn();
+            original.is_superseded = true;
+            ctx.accounts.appellant.to_account_info()
+        } else {
+            appeal.is_superseded = true;
+            ctx.accounts.insurance_pool.total_received += appeal.appeal_bond;
+            ctx.accounts.insurance_pool.to_account_info()
+        };
+        pay_out(
+            appeal,
+            &ctx.accounts.appeal_vault,
+            &bond_recipient,
+            &ctx.accounts.system_program,
+            appeal.appeal_bond,
+        )?;
+        appeal.appeal_bond = 0;
+
+        original.resolve_appeal(now);
+        appeal.appeal_resolved = true;
+
+        emit!(AppealResolved {
+            original: original.key(),
+            appeal: appeal.key(),
+            overturned,
+        });
+        original.assert_invariants()?;
+        appeal.assert_invariants()?;
+        Ok(())
+    }
+
+    /// Stakes `amount` lamports, which must lie within the credential's
+    /// `[min_stake, max_stake]` range.
+    pub fn stake_for_credential(ctx: Context<StakeForCredential>, amount: u64) -> Result<()> {
+        let credential = &mut ctx.accounts.credential;
+        let verifier = &mut ctx.accounts.verifier;
+        let clock = Clock::get()?;
+
+        let reputation = &mut ctx.accounts.reputation;
+        reputation.init_if_new(
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+            ctx.bumps.reputation,
+        );
+        check_eligibility(
+            &credential.eligibility,
+            credential.require_pool,
+            ctx.accounts.allowlist_entry.is_some(),
+            ctx.accounts.pool_membership.is_some(),
+            reputation,
+            clock.unix_timestamp,
+        )?;
+        let credential_key = credential.key();
+        credential.add_stake(
+            credential_key,
+            verifier,
+            ctx.accounts.authority.key(),
+            amount,
+            reputation.score(),
+            clock.unix_timestamp,
+        )?;
+
         let transfer_instruction = anchor_lang::system_program::Transfer {
             from: ctx.accounts.authority.to_account_info(),
-            to: credential.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
         };
 
         let cpi_context = CpiContext::new(
@@ -57,211 +335,1979 @@ pub mod credential_verifier {
             transfer_instruction,
         );
 
-        anchor_lang::system_program::transfer(cpi_context, credential.stake_amount)?;
+        anchor_lang::system_program::transfer(cpi_context, amount)?;
 
-        verifier.credential = credential.key();
-        verifier.authority = ctx.accounts.authority.key();
-        verifier.has_voted = false;
-        verifier.has_claimed = false;
-        credential.total_staked += credential.stake_amount;
-        credential.verifier_count += 1;
+        credential.assert_invariants()?;
+        Ok(())
+    }
 
+    /// Locks in a hidden vote; see `vote_commitment` for the hash layout.
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+        let credential = &mut ctx.accounts.credential;
+        let credential_key = credential.key();
+        credential.commit(
+            credential_key,
+            &mut ctx.accounts.verifier,
+            commitment,
+            Clock::get()?.unix_timestamp,
+        )?;
+        credential.assert_invariants()?;
+        Ok(())
+    }
+
+    /// Opens a committed vote. Only revealed votes are tallied; a commit that
+    /// is never revealed forfeits the whole stake, like a minority vote, so
+    /// withholding a losing reveal never pays.
+    pub fn reveal_vote(ctx: Context<RevealVote>, is_authentic: bool, salt: [u8; 32]) -> Result<()> {
+        let credential = &mut ctx.accounts.credential;
+        let credential_key = credential.key();
+        credential.reveal(
+            credential_key,
+            &mut ctx.accounts.verifier,
+            is_authentic,
+            &salt,
+            Clock::get()?.unix_timestamp,
+        )?;
+        emit_if_escalated(credential);
+        credential.assert_invariants()?;
+        Ok(())
+    }
+
+    /// Resolves a round whose reveal window closed before every staker
+    /// revealed, using only the votes that were revealed.
+    pub fn finalize_after_deadline(ctx: Context<FinalizeAfterDeadline>) -> Result<()> {
+        let credential = &mut ctx.accounts.credential;
+        credential.finalize_expired(Clock::get()?.unix_timestamp)?;
+        emit_if_escalated(credential);
+        credential.assert_invariants()?;
         Ok(())
     }
 
-    pub fn make_decision(ctx: Context<MakeDecision>, is_authentic: bool) -> Result<()> {
+    /// Pays a winner's share, optionally closing its `Verifier` account once
+    /// settlement no longer needs it.
+    pub fn claim_reward(ctx: Context<ClaimReward>, close_verifier: bool) -> Result<()> {
         let credential = &mut ctx.accounts.credential;
         let verifier = &mut ctx.accounts.verifier;
 
-        require!(!credential.is_finalized, VerifierError::AlreadyFinalized);
-        require!(
-            verifier.credential == credential.key(),
-            VerifierError::NotStaked
-        );
-        require!(!verifier.has_voted, VerifierError::AlreadyVoted);
+        let payout = credential.claim(verifier, Clock::get()?.unix_timestamp)?;
+        pay_out(
+            credential,
+            &ctx.accounts.vault,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            payout,
+        )?;
 
-        credential.verifications += 1;
-        if is_authentic {
-            credential.authentic_votes += 1;
+        let reputation = &mut ctx.accounts.reputation;
+        reputation.votes += 1;
+        if credential.outcome != Outcome::Tie {
+            reputation.majority_hits += 1;
         }
+        reputation.lifetime_earnings += payout.saturating_sub(verifier.stake);
 
-        verifier.voted_authentic = is_authentic;
-        verifier.has_voted = true;
+        if close_verifier {
+            require!(
+                !credential.needs_verifier_records(),
+                VerifierError::VerifierStillNeeded
+            );
+            verifier.close(ctx.accounts.authority.to_account_info())?;
+        }
+        credential.assert_invariants()?;
+        Ok(())
+    }
+
+    /// Slashes a minority or non-voting verifier once the round is final.
+    /// Anyone may call it; the penalty is split between the insurance pool and
+    /// the winners, and a staker that never committed gets the unslashed part
+    /// of its stake back.
+    pub fn slash_verifier(ctx: Context<SlashVerifier>) -> Result<()> {
+        let credential = &mut ctx.accounts.credential;
+        let verifier = &mut ctx.accounts.verifier;
 
-        if credential.verifications == 10 {
-            credential.is_finalized = true;
+        let slash = credential.slash(verifier, Clock::get()?.unix_timestamp)?;
+        pay_out(
+            credential,
+            &ctx.accounts.vault,
+            &ctx.accounts.insurance_pool.to_account_info(),
+            &ctx.accounts.system_program,
+            slash.to_treasury,
+        )?;
+        pay_out(
+            credential,
+            &ctx.accounts.vault,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            slash.refund,
+        )?;
+        ctx.accounts.insurance_pool.total_received += slash.to_treasury;
+
+        let reputation = &mut ctx.accounts.reputation;
+        if verifier.has_voted {
+            reputation.votes += 1;
         }
+        reputation.slashes += 1;
 
+        emit!(SlashEvent {
+            credential: credential.key(),
+            verifier: verifier.authority,
+            voted: verifier.has_voted,
+            amount: slash.penalty,
+            to_treasury: slash.to_treasury,
+            to_winners: slash.to_winners,
+        });
+        credential.assert_invariants()?;
         Ok(())
     }
 
-    pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
+    /// Writes the finalized outcome back to the tablu credential, recording the
+    /// majority voters passed in as `remaining_accounts`.
+    pub fn settle_verification<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleVerification<'info>>,
+    ) -> Result<()> {
         let credential = &mut ctx.accounts.credential;
-        let verifier = &mut ctx.accounts.verifier;
 
         require!(credential.is_finalized, VerifierError::NotFinalized);
-        require!(!verifier.has_claimed, VerifierError::AlreadyClaimed);
-        require!(verifier.has_voted, VerifierError::NotVoted);
+        require!(!credential.is_settled, VerifierError::AlreadySettled);
+        require!(!credential.is_superseded, VerifierError::RoundSuperseded);
+        require!(
+            credential.outcome != Outcome::Tie,
+            VerifierError::NoMajority
+        );
+        // Until the appeal is resolved it is unknown which round settles.
+        require!(
+            (credential.appeal == Pubkey::default() && credential.appeal_of == Pubkey::default())
+                || credential.appeal_resolved,
+            VerifierError::AppealUnresolved
+        );
+        require!(
+            credential.payouts_open(Clock::get()?.unix_timestamp),
+            VerifierError::PayoutsLocked
+        );
 
-        let majority_voted_authentic = credential.authentic_votes > 5;
-        let verifier_in_majority = verifier.voted_authentic == majority_voted_authentic;
+        let target = tablu::load_credential(
+            &ctx.accounts.target_credential,
+            credential.credential_type.clone(),
+        )?;
+        require!(
+            *target.status() == VerificationStatus::Pending
+                && target.revision() == credential.target_revision,
+            VerifierError::TargetChanged
+        );
 
-        if verifier_in_majority {
-            let majority_count = if majority_voted_authentic {
-                credential.authentic_votes
-            } else {
-                10 - credential.authentic_votes
-            };
+        let majority_voted_authentic = credential.majority_authentic();
+        let majority_count = credential.winner_count();
 
-            let reward_amount = credential
-                .total_staked
-                .checked_div(majority_count as u64)
-                .unwrap();
+        let mut majority_voters = Vec::with_capacity(majority_count as usize);
+        for account in ctx.remaining_accounts.iter() {
+            let verifier = Account::<Verifier>::try_from(account)?;
+            require!(
+                verifier.credential == credential.key(),
+                VerifierError::NotStaked
+            );
+            require!(
+                verifier.round_id == credential.round_id,
+                VerifierError::StaleVerifier
+            );
+            require!(verifier.has_voted, VerifierError::NotVoted);
+            require!(
+                verifier.voted_authentic == majority_voted_authentic,
+                VerifierError::NotInMajority
+            );
+            require!(
+                !majority_voters.contains(&verifier.authority),
+                VerifierError::DuplicateVoter
+            );
+            majority_voters.push(verifier.authority);
+        }
+        require!(
+            majority_voters.len() == majority_count as usize,
+            VerifierError::IncompleteMajority
+        );
+
+        let new_status = if majority_voted_authentic {
+            VerificationStatus::Verified
+        } else {
+            VerificationStatus::Rejected
+        };
+
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[b"settlement_authority", &[ctx.bumps.settlement_authority]]];
+        let tablu_program = ctx.accounts.tablu_program.to_account_info();
+        let target = ctx.accounts.target_credential.to_account_info();
+        let profile = ctx.accounts.target_profile.to_account_info();
+        let config = ctx.accounts.tablu_config.to_account_info();
+        let authority = ctx.accounts.settlement_authority.to_account_info();
 
-            **credential.to_account_info().try_borrow_mut_lamports()? -= reward_amount;
-            **ctx.accounts.authority.try_borrow_mut_lamports()? += reward_amount;
+        match credential.credential_type {
+            CredentialType::Degree => tablu::cpi::update_degree_verification_status(
+                CpiContext::new_with_signer(
+                    tablu_program,
+                    UpdateDegreeVerification {
+                        credential: target,
+                        profile,
+                        config,
+                        authority,
+                    },
+                    signer_seeds,
+                ),
+                new_status,
+                majority_voters,
+            )?,
+            CredentialType::Project => tablu::cpi::update_project_verification_status(
+                CpiContext::new_with_signer(
+                    tablu_program,
+                    UpdateProjectVerification {
+                        credential: target,
+                        profile,
+                        config,
+                        authority,
+                    },
+                    signer_seeds,
+                ),
+                new_status,
+                majority_voters,
+            )?,
+            CredentialType::Skill => tablu::cpi::update_skill_verification_status(
+                CpiContext::new_with_signer(
+                    tablu_program,
+                    UpdateSkillVerification {
+                        credential: target,
+                        profile,
+                        config,
+                        authority,
+                    },
+                    signer_seeds,
+                ),
+                new_status,
+                majority_voters,
+            )?,
+            CredentialType::Employment => tablu::cpi::update_employment_verification_status(
+                CpiContext::new_with_signer(
+                    tablu_program,
+                    UpdateEmploymentVerification {
+                        credential: target,
+                        profile,
+                        config,
+                        authority,
+                    },
+                    signer_seeds,
+                ),
+                new_status,
+                majority_voters,
+            )?,
+            CredentialType::Certificate => tablu::cpi::update_certificate_verification_status(
+                CpiContext::new_with_signer(
+                    tablu_program,
+                    UpdateCertificateVerification {
+                        credential: target,
+                        profile,
+                        config,
+                        authority,
+                    },
+                    signer_seeds,
+                ),
+                new_status,
+                majority_voters,
+            )?,
         }
 
-        verifier.has_claimed = true;
+        credential.is_settled = true;
+        credential.assert_invariants()?;
+        Ok(())
+    }
+
+    /// Cancels a round whose staking window closed without a full set of
+    /// stakers. Anyone may call it; cancellation is terminal and is the only
+    /// state stakes can be refunded from.
+    pub fn cancel_credential(ctx: Context<CancelCredential>) -> Result<()> {
+        let credential = &mut ctx.accounts.credential;
+        credential.cancel(Clock::get()?.unix_timestamp)?;
+        credential.assert_invariants()?;
+        Ok(())
+    }
+
+    /// Marks a round whose target was edited, withdrawn or revoked while it
+    /// ran, so it can never be settled. Anyone may call it. A round still in
+    /// progress is cancelled so its stakes can be refunded; a finalized one
+    /// keeps its payouts but no longer waits on settlement before closing.
+    pub fn supersede_round(ctx: Context<SupersedeRound>) -> Result<()> {
+        let credential = &mut ctx.accounts.credential;
+
+        require!(!credential.is_settled, VerifierError::AlreadySettled);
+        require!(!credential.is_superseded, VerifierError::RoundSuperseded);
+        require!(!credential.is_cancelled, VerifierError::Cancelled);
+
+        let target_info = &ctx.accounts.target_credential;
+        let target_changed = target_info.data_is_empty() || *target_info.owner != tablu::ID || {
+            let target = tablu::load_credential(target_info, credential.credential_type.clone())?;
+            *target.status() != VerificationStatus::Pending
+                || target.revision() != credential.target_revision
+        };
+        require!(target_changed, VerifierError::TargetUnchanged);
+
+        credential.is_superseded = true;
+        if !credential.is_finalized {
+            credential.is_cancelled = true;
+        }
+
+        emit!(RoundSuperseded {
+            credential: credential.key(),
+            target: credential.target,
+        });
+        credential.assert_invariants()?;
         Ok(())
     }
 
-    pub fn refund_expired_stakes(ctx: Context<RefundExpiredStakes>) -> Result<()> {
+    /// Returns a staker's full stake from a cancelled round, whether or not
+    /// it had voted.
+    pub fn refund_expired_stakes(
+        ctx: Context<RefundExpiredStakes>,
+        close_verifier: bool,
+    ) -> Result<()> {
         let credential = &mut ctx.accounts.credential;
         let verifier = &mut ctx.accounts.verifier;
-        let clock = Clock::get()?;
 
-        let time_elapsed = clock.unix_timestamp - credential.created_at;
-        let five_days_in_secs = 5 * 24 * 60 * 60;
+        let credential_key = credential.key();
+        let refund_amount = credential.refund(credential_key, verifier)?;
+        pay_out(
+            credential,
+            &ctx.accounts.vault,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            refund_amount,
+        )?;
+
+        if close_verifier {
+            verifier.close(ctx.accounts.authority.to_account_info())?;
+        }
+        credential.assert_invariants()?;
+        Ok(())
+    }
+
+    /// Closes a round once every verifier has been paid, slashed or refunded,
+    /// returning the vault and account rent to the creator. Rewards left
+    /// unclaimed past the claim deadline go to the insurance pool.
+    pub fn close_credential(ctx: Context<CloseCredential>) -> Result<()> {
+        let credential = &ctx.accounts.credential;
 
         require!(
-            time_elapsed >= five_days_in_secs,
-            VerifierError::TimeoutNotReached
-        );
-        require!(
-            credential.verifier_count < 10,
-            VerifierError::EnoughVerifiers
-        );
-        require!(!verifier.has_claimed, VerifierError::AlreadyClaimed);
-        require!(
-            verifier.credential == credential.key(),
-            VerifierError::NotStaked
+            credential.is_closable(Clock::get()?.unix_timestamp),
+            VerifierError::NotClosable
         );
 
-        let refund_amount = credential.stake_amount;
-
-        **credential.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
-        **ctx.accounts.authority.try_borrow_mut_lamports()? += refund_amount;
+        let unclaimed = if credential.is_finalized {
+            credential.remaining_pool
+        } else {
+            0
+        };
+        pay_out(
+            credential,
+            &ctx.accounts.vault,
+            &ctx.accounts.insurance_pool.to_account_info(),
+            &ctx.accounts.system_program,
+            unclaimed,
+        )?;
+        ctx.accounts.insurance_pool.total_received += unclaimed;
 
-        verifier.has_claimed = true;
-        credential.total_staked -= refund_amount;
-        credential.verifier_count -= 1;
+        let vault_balance = ctx.accounts.vault.lamports();
+        transfer_from_vault(
+            credential,
+            &ctx.accounts.vault,
+            &ctx.accounts.creator,
+            &ctx.accounts.system_program,
+            vault_balance,
+        )?;
+        Ok(())
+    }
 
+    /// Returns a `Verifier` account's rent to its authority once nothing more
+    /// can happen to it: it was paid, slashed or refunded, or it missed the
+    /// claim deadline, or its round has already been closed, possibly with a
+    /// new round since opened at the same address.
+    pub fn close_verifier(ctx: Context<CloseVerifier>) -> Result<()> {
+        let credential_info = &ctx.accounts.credential;
+        if !credential_info.data_is_empty() {
+            require_keys_eq!(
+                *credential_info.owner,
+                crate::ID,
+                ErrorCode::AccountOwnedByWrongProgram
+            );
+            let data = credential_info.try_borrow_data()?;
+            let credential = Credential::try_deserialize(&mut &data[..])?;
+            let now = Clock::get()?.unix_timestamp;
+            require!(
+                credential.round_id != ctx.accounts.verifier.round_id
+                    || credential.verifier_is_terminal(&ctx.accounts.verifier, now),
+                VerifierError::VerifierNotTerminal
+            );
+        }
         Ok(())
     }
 }
 
 #[derive(Accounts)]
-#[instruction(credential_id: String)]
-pub struct InitializeCredential<'info> {
+pub struct InitializeConfig<'info> {
     #[account(
         init,
-        payer = authority,
-        space = 8 + 4 + credential_id.len() + 8 + 4 + 4 + 8 + 1 + 8 + 4,
-        seeds = [b"credential", credential_id.as_bytes()],
+        payer = admin,
+        space = 8 + ProtocolConfig::INIT_SPACE,
+        seeds = [b"config"],
         bump
     )]
-    pub credential: Account<'info, Credential>,
+    pub config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + InsurancePool::INIT_SPACE,
+        seeds = [b"insurance_pool"],
+        bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub admin: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::CredentialVerifier>,
+    #[account(
+        constraint = tablu::is_upgrade_authority(&program_data, admin.key)
+            @ VerifierError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct StakeForCredential<'info> {
-    #[account(mut)]
-    pub credential: Account<'info, Credential>,
+pub struct UpdateSlashingConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ VerifierError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetEligibilityRule<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ VerifierError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetRequirePool<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ VerifierError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAppealBond<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ VerifierError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetRoundLimits<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ VerifierError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(verifier: Pubkey)]
+pub struct AddToAllowlist<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ VerifierError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
     #[account(
         init,
-        payer = authority,
-        space = 8 + 32 + 32 + 1 + 1 + 1 + 32,
-        seeds = [b"verifier", credential.key().as_ref(), authority.key().as_ref()],
+        payer = admin,
+        space = 8 + AllowlistEntry::INIT_SPACE,
+        seeds = [b"allowlist", verifier.as_ref()],
         bump
     )]
-    pub verifier: Account<'info, Verifier>,
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub admin: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct MakeDecision<'info> {
-    #[account(mut)]
-    pub credential: Account<'info, Credential>,
+pub struct RemoveFromAllowlist<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ VerifierError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
     #[account(
         mut,
-        seeds = [b"verifier", credential.key().as_ref(), authority.key().as_ref()],
-        bump,
-        has_one = authority
+        close = admin,
+        seeds = [b"allowlist", allowlist_entry.verifier.as_ref()],
+        bump = allowlist_entry.bump
     )]
-    pub verifier: Account<'info, Verifier>,
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct ClaimReward<'info> {
+#[instruction(credential_type: CredentialType, tag: String, member: Pubkey)]
+pub struct AddPoolMember<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ VerifierError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PoolMembership::INIT_SPACE,
+        seeds = [
+            b"pool".as_ref(),
+            &pool_type_seed(&credential_type),
+            tag.as_bytes(),
+            member.as_ref()
+        ],
+        bump
+    )]
+    pub pool_membership: Account<'info, PoolMembership>,
     #[account(mut)]
-    pub credential: Account<'info, Credential>,
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemovePoolMember<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ VerifierError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
     #[account(
         mut,
-        seeds = [b"verifier", credential.key().as_ref(), authority.key().as_ref()],
-        bump,
-        has_one = authority
+        close = admin,
+        seeds = [
+            b"pool".as_ref(),
+            &pool_type_seed(&pool_membership.credential_type),
+            pool_membership.tag.as_bytes(),
+            pool_membership.member.as_ref()
+        ],
+        bump = pool_membership.bump
     )]
-    pub verifier: Account<'info, Verifier>,
+    pub pool_membership: Account<'info, PoolMembership>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterVerifier<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + VerifierReputation::INIT_SPACE,
+        seeds = [b"reputation", authority.key().as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, VerifierReputation>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct RefundExpiredStakes<'info> {
-    #[account(mut)]
-    pub credential: Account<'info, Credential>,
+pub struct InitializeCredential<'info> {
     #[account(
-        mut,
-        seeds = [b"verifier", credential.key().as_ref(), authority.key().as_ref()],
-        bump,
-        has_one = authority
+        init,
+        payer = authority,
+        space = 8 + Credential::INIT_SPACE,
+        seeds = [b"credential", target_credential.key().as_ref()],
+        bump
     )]
-    pub verifier: Account<'info, Verifier>,
+    pub credential: Account<'info, Credential>,
+    #[account(mut, seeds = [b"vault", credential.key().as_ref()], bump)]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: Deserialized as `credential_type` by `tablu::load_credential`, which checks the owner
+    pub target_credential: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct OpenAppeal<'info> {
+    #[account(mut, constraint = original.appeal_of == Pubkey::default() @ VerifierError::NotAppealable)]
+    pub original: Account<'info, Credential>,
+    #[account(
+        init,
+        payer = appellant,
+        space = 8 + Credential::INIT_SPACE,
+        seeds = [b"appeal", original.key().as_ref()],
+        bump
+    )]
+    pub appeal: Account<'info, Credential>,
+    #[account(mut, seeds = [b"vault", appeal.key().as_ref()], bump)]
+    pub vault: SystemAccount<'info>,
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub appellant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveAppeal<'info> {
+    #[account(mut)]
+    pub original: Account<'info, Credential>,
+    #[account(mut, seeds = [b"appeal", original.key().as_ref()], bump)]
+    pub appeal: Account<'info, Credential>,
+    #[account(mut, seeds = [b"vault", appeal.key().as_ref()], bump = appeal.vault_bump)]
+    pub appeal_vault: SystemAccount<'info>,
+    /// CHECK: Receives the bond back when the appeal succeeds
+    #[account(mut, address = appeal.appellant)]
+    pub appellant: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"insurance_pool"], bump = insurance_pool.bump)]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct StakeForCredential<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+    #[account(mut, seeds = [b"vault", credential.key().as_ref()], bump = credential.vault_bump)]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Verifier::INIT_SPACE,
+        seeds = [
+            b"verifier",
+            credential.key().as_ref(),
+            &credential.round_id.to_le_bytes(),
+            authority.key().as_ref()
+        ],
+        bump
+    )]
+    pub verifier: Account<'info, Verifier>,
+    /// Required under `EligibilityRule::Allowlist`.
+    #[account(seeds = [b"allowlist", authority.key().as_ref()], bump = allowlist_entry.bump)]
+    pub allowlist_entry: Option<Account<'info, AllowlistEntry>>,
+    /// Required when the credential's `require_pool` is set.
+    #[account(
+        seeds = [
+            b"pool".as_ref(),
+            &pool_type_seed(&credential.credential_type),
+            credential.pool_tag.as_bytes(),
+            authority.key().as_ref()
+        ],
+        bump = pool_membership.bump
+    )]
+    pub pool_membership: Option<Account<'info, PoolMembership>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + VerifierReputation::INIT_SPACE,
+        seeds = [b"reputation", authority.key().as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, VerifierReputation>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+    #[account(
+        mut,
+        seeds = [
+            b"verifier",
+            credential.key().as_ref(),
+            &credential.round_id.to_le_bytes(),
+            authority.key().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub verifier: Account<'info, Verifier>,
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+    #[account(
+        mut,
+        seeds = [
+            b"verifier",
+            credential.key().as_ref(),
+            &credential.round_id.to_le_bytes(),
+            authority.key().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub verifier: Account<'info, Verifier>,
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeAfterDeadline<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReward<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+    #[account(mut, seeds = [b"vault", credential.key().as_ref()], bump = credential.vault_bump)]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"verifier",
+            credential.key().as_ref(),
+            &credential.round_id.to_le_bytes(),
+            authority.key().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub verifier: Account<'info, Verifier>,
+    #[account(
+        mut,
+        seeds = [b"reputation", authority.key().as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, VerifierReputation>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SlashVerifier<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+    #[account(mut, seeds = [b"vault", credential.key().as_ref()], bump = credential.vault_bump)]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        constraint = verifier.credential == credential.key() @ VerifierError::NotStaked,
+        constraint = verifier.round_id == credential.round_id @ VerifierError::StaleVerifier
+    )]
+    pub verifier: Account<'info, Verifier>,
+    /// CHECK: Receives the unslashed part of a non-committer's stake
+    #[account(mut, address = verifier.authority)]
+    pub authority: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"reputation", verifier.authority.as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, VerifierReputation>,
+    #[account(mut, seeds = [b"insurance_pool"], bump = insurance_pool.bump)]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelCredential<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+}
+
+#[derive(Accounts)]
+pub struct SupersedeRound<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+    /// CHECK: Only inspected; it may have been closed or reassigned since the round opened
+    #[account(address = credential.target @ VerifierError::TargetMismatch)]
+    pub target_credential: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RefundExpiredStakes<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+    #[account(mut, seeds = [b"vault", credential.key().as_ref()], bump = credential.vault_bump)]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"verifier",
+            credential.key().as_ref(),
+            &credential.round_id.to_le_bytes(),
+            authority.key().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub verifier: Account<'info, Verifier>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseCredential<'info> {
+    #[account(mut, close = creator, has_one = creator)]
+    pub credential: Account<'info, Credential>,
+    #[account(mut, seeds = [b"vault", credential.key().as_ref()], bump = credential.vault_bump)]
+    pub vault: SystemAccount<'info>,
+    /// CHECK: Receives the account and vault rent
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"insurance_pool"], bump = insurance_pool.bump)]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseVerifier<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"verifier",
+            verifier.credential.as_ref(),
+            &verifier.round_id.to_le_bytes(),
+            authority.key().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub verifier: Account<'info, Verifier>,
+    /// CHECK: The verifier's round; it may already have been closed
+    #[account(address = verifier.credential)]
+    pub credential: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleVerification<'info> {
+    #[account(mut)]
+    pub credential: Account<'info, Credential>,
+    /// CHECK: Validated against `credential.target` and deserialized by the tablu program
+    #[account(mut, address = credential.target @ VerifierError::TargetMismatch)]
+    pub target_credential: UncheckedAccount<'info>,
+    /// CHECK: Profile of the credential owner, validated by the tablu program
+    #[account(mut)]
+    pub target_profile: UncheckedAccount<'info>,
+    /// CHECK: Tablu program config, validated by the tablu program
+    pub tablu_config: UncheckedAccount<'info>,
+    /// CHECK: PDA signer registered as the tablu config's verifier authority
+    #[account(seeds = [b"settlement_authority"], bump)]
+    pub settlement_authority: UncheckedAccount<'info>,
+    pub tablu_program: Program<'info, Tablu>,
+}
+
 #[account]
-#[derive(Default)]
+#[derive(InitSpace)]
 pub struct Credential {
-    pub credential_id: String,
-    pub stake_amount: u64,
-    pub verifications: u32,
+    /// Unique across every round ever opened, including rounds re-created at
+    /// the same address after a close, and part of each `Verifier`'s seeds.
+    pub round_id: u64,
+    pub voting_mode: VotingMode,
+    pub min_stake: u64,
+    pub max_stake: u64,
+    /// Revealed votes.
+    pub vote_count: u32,
     pub authentic_votes: u32,
+    /// Vote weight, stake and winners' cut of the forfeitable stake, summed
+    /// over revealed votes and over the authentic ones among them.
+    pub revealed_weight: u64,
+    pub authentic_weight: u64,
+    pub revealed_stake: u64,
+    pub authentic_stake: u64,
+    pub revealed_forfeit: u64,
+    pub authentic_forfeit: u64,
+    /// Winners' cut of a full stake, summed over stakers who committed.
+    pub committed_forfeit: u64,
+    /// Winners' cut of the non-voter penalty, summed over every staker and
+    /// over those who committed.
+    pub abstain_forfeit: u64,
+    pub committed_abstain_forfeit: u64,
     pub total_staked: u64,
     pub is_finalized: bool,
     pub created_at: i64,
-    pub verifier_count: u32,
+    /// Wallets that staked, including any later refunded. Capped by `quorum`.
+    pub staker_count: u32,
+    /// Tablu credential account this verification round settles into.
+    pub target: Pubkey,
+    pub credential_type: CredentialType,
+    /// Revision of the target when the round opened; an edit by its owner
+    /// invalidates the round.
+    pub target_revision: u32,
+    /// Wallet that submitted the target; it may not verify its own credential.
+    pub target_owner: Pubkey,
+    pub eligibility: EligibilityRule,
+    /// Minimum `VerifierReputation::score` a wallet needs to stake.
+    pub min_reputation: u32,
+    /// Whether only members of the verifier pool may stake, on top of the
+    /// eligibility rule.
+    pub require_pool: bool,
+    /// Pool within `credential_type` whose members may stake when
+    /// `require_pool` is set; empty for the type-wide pool.
+    #[max_len(MAX_POOL_TAG_LEN)]
+    pub pool_tag: String,
+    pub is_settled: bool,
+    /// Set when the round's outcome will never be written back: its target
+    /// changed under it, in which case a round still in progress is cancelled
+    /// too, or an appeal decided which round settles and it was not this one.
+    pub is_superseded: bool,
+    /// Terminal state for rounds that never gathered enough stakers.
+    pub is_cancelled: bool,
+    /// Stakers refunded from a cancelled round.
+    pub refunded_count: u32,
+    /// Lamports still owed to stakers before finalization, and to the winners
+    /// after it; `total_staked` records the deposits.
+    pub remaining_pool: u64,
+    /// Winners that have been paid out.
+    pub claimed_count: u32,
+    pub quorum: u32,
+    /// Share of votes, in basis points, one side needs to win.
+    pub majority_bps: u16,
+    pub tie_policy: TiePolicy,
+    pub outcome: Outcome,
+    pub staking_window: i64,
+    pub voting_window: i64,
+    pub claim_window: i64,
+    pub staking_deadline: i64,
+    /// End of the commit phase, set once every staking slot is filled; zero
+    /// while staking is open.
+    pub voting_deadline: i64,
+    pub reveal_window: i64,
+    /// Set together with `voting_deadline`, and brought forward once every
+    /// staker has committed.
+    pub reveal_deadline: i64,
+    pub commit_count: u32,
+    /// Set on finalization; zero until then.
+    pub claim_deadline: i64,
+    /// Seconds after finalization during which the outcome may be appealed;
+    /// zero for appeal rounds, which cannot be appealed again.
+    pub appeal_window: i64,
+    pub appeal_deadline: i64,
+    /// Appeal round challenging this one, if any.
+    pub appeal: Pubkey,
+    /// On a first round, its appeal has been resolved; on an appeal round, it
+    /// has been applied to the round it challenged.
+    pub appeal_resolved: bool,
+    /// Set when an appeal reversed this round's outcome.
+    pub overturned: bool,
+    /// Round this one appeals, if it is an appeal.
+    pub appeal_of: Pubkey,
+    pub appellant: Pubkey,
+    /// Lamports held on top of the stakes until the appeal is resolved.
+    pub appeal_bond: u64,
+    /// Paid the account and vault rent, and gets both back on close.
+    pub creator: Pubkey,
+    pub vault_bump: u8,
+    /// Share of every slashed stake sent to the insurance pool.
+    pub treasury_share_bps: u16,
+    /// Share of the stake slashed from a staker that never committed.
+    pub non_voter_slash_bps: u16,
+    /// Winners' stakes plus their share of the penalties, fixed on finalization.
+    pub reward_pool: u64,
+    /// Minority and non-voting verifiers that have been slashed.
+    pub slashed_count: u32,
+}
+
+impl Credential {
+    /// Validates `params` against the config's `RoundLimits` and resets every
+    /// per-round field, shared by first rounds and appeals. Target fields are
+    /// filled in by the caller.
+    pub fn open_round(
+        &mut self,
+        params: RoundParams,
+        config: &mut ProtocolConfig,
+        now: i64,
+    ) -> Result<()> {
+        let limits = &config.round_limits;
+        require!(
+            (limits.min_quorum..=MAX_QUORUM).contains(&params.quorum),
+            VerifierError::InvalidQuorum
+        );
+        require!(
+            params.pool_tag.len() <= MAX_POOL_TAG_LEN,
+            VerifierError::PoolTagTooLong
+        );
+        // Above 50% so that both sides can never reach the threshold at once.
+        require!(
+            u64::from(params.majority_bps) * 2 > BPS_DENOMINATOR
+                && u64::from(params.majority_bps) <= BPS_DENOMINATOR,
+            VerifierError::InvalidMajority
+        );
+        let window_range = limits.min_window..=limits.max_window;
+        require!(
+            window_range.contains(&params.staking_window)
+                && window_range.contains(&params.voting_window)
+                && window_range.contains(&params.reveal_window)
+                && window_range.contains(&params.claim_window)
+                && (0..=limits.max_window).contains(&params.appeal_window),
+            VerifierError::InvalidWindow
+        );
+        require!(
+            params.min_stake >= limits.min_stake
+                && params.min_stake <= params.max_stake
+                && (params.voting_mode != VotingMode::Equal
+                    || params.min_stake == params.max_stake),
+            VerifierError::InvalidStakeRange
+        );
+
+        self.round_id = config.rounds_opened;
+        config.rounds_opened += 1;
+        self.voting_mode = params.voting_mode;
+        self.min_stake = params.min_stake;
+        self.max_stake = params.max_stake;
+        self.quorum = params.quorum;
+        self.majority_bps = params.majority_bps;
+        self.tie_policy = params.tie_policy;
+        self.staking_window = params.staking_window;
+        self.voting_window = params.voting_window;
+        self.reveal_window = params.reveal_window;
+        self.claim_window = params.claim_window;
+        self.staking_deadline = now + params.staking_window;
+        self.voting_deadline = 0;
+        self.reveal_deadline = 0;
+        self.claim_deadline = 0;
+        // Rates are fixed for the round so stakers know the terms up front.
+        self.treasury_share_bps = config.treasury_share_bps;
+        self.non_voter_slash_bps = config.non_voter_slash_bps;
+        self.eligibility = config.eligibility.clone();
+        self.require_pool = config.require_pool;
+        self.min_reputation = params.min_reputation;
+        self.pool_tag = params.pool_tag;
+        self.reward_pool = 0;
+        self.slashed_count = 0;
+        self.outcome = Outcome::Undecided;
+        self.staker_count = 0;
+        self.commit_count = 0;
+        self.vote_count = 0;
+        self.authentic_votes = 0;
+        self.revealed_weight = 0;
+        self.authentic_weight = 0;
+        self.revealed_stake = 0;
+        self.authentic_stake = 0;
+        self.revealed_forfeit = 0;
+        self.authentic_forfeit = 0;
+        self.abstain_forfeit = 0;
+        self.committed_forfeit = 0;
+        self.committed_abstain_forfeit = 0;
+        self.total_staked = 0;
+        self.remaining_pool = 0;
+        self.claimed_count = 0;
+        self.is_finalized = false;
+        self.is_settled = false;
+        self.is_superseded = false;
+        self.is_cancelled = false;
+        self.refunded_count = 0;
+        self.created_at = now;
+        Ok(())
+    }
+
+    /// Records `verifier`'s stake of `amount` lamports; the caller checks the
+    /// eligibility rule and moves the lamports into the vault.
+    pub fn add_stake(
+        &mut self,
+        credential_key: Pubkey,
+        verifier: &mut Verifier,
+        authority: Pubkey,
+        amount: u64,
+        reputation_score: u32,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            now <= self.staking_deadline,
+            VerifierError::StakingPeriodEnded
+        );
+        require!(
+            self.staker_count < self.quorum,
+            VerifierError::MaxVerifiersReached
+        );
+        require!(!self.is_finalized, VerifierError::AlreadyFinalized);
+        require!(!self.is_cancelled, VerifierError::Cancelled);
+        require!(
+            verifier.credential == Pubkey::default(),
+            VerifierError::AlreadyStaked
+        );
+        require_keys_neq!(
+            authority,
+            self.target_owner,
+            VerifierError::SelfVerification
+        );
+        require!(
+            reputation_score >= self.min_reputation,
+            VerifierError::ReputationTooLow
+        );
+        require!(
+            (self.min_stake..=self.max_stake).contains(&amount),
+            VerifierError::InvalidStakeAmount
+        );
+        let weight = self.vote_weight(amount, reputation_score)?;
+
+        verifier.credential = credential_key;
+        verifier.round_id = self.round_id;
+        verifier.authority = authority;
+        verifier.stake = amount;
+        verifier.weight = weight;
+        verifier.has_committed = false;
+        verifier.has_voted = false;
+        verifier.has_claimed = false;
+        self.total_staked += amount;
+        self.remaining_pool += amount;
+        self.abstain_forfeit += self.winners_cut(self.penalty_for(false, amount));
+        self.staker_count += 1;
+
+        // A full set of stakers closes staking and opens the voting window.
+        if self.staker_count == self.quorum {
+            self.voting_deadline = now + self.voting_window;
+            self.reveal_deadline = self.voting_deadline + self.reveal_window;
+        }
+        Ok(())
+    }
+
+    pub fn commit(
+        &mut self,
+        credential_key: Pubkey,
+        verifier: &mut Verifier,
+        commitment: [u8; 32],
+        now: i64,
+    ) -> Result<()> {
+        require!(!self.is_finalized, VerifierError::AlreadyFinalized);
+        require!(!self.is_cancelled, VerifierError::Cancelled);
+        require!(
+            verifier.credential == credential_key,
+            VerifierError::NotStaked
+        );
+        require!(!verifier.has_committed, VerifierError::AlreadyVoted);
+        require!(self.voting_deadline != 0, VerifierError::VotingNotOpen);
+        require!(
+            now <= self.voting_deadline,
+            VerifierError::VotingPeriodEnded
+        );
+
+        verifier.commitment = commitment;
+        verifier.has_committed = true;
+        self.commit_count += 1;
+        self.committed_forfeit += self.winners_cut(self.penalty_for(true, verifier.stake));
+        self.committed_abstain_forfeit += self.winners_cut(self.penalty_for(false, verifier.stake));
+
+        // Once every staker has committed there is nothing left to hide.
+        if self.commit_count == self.quorum {
+            self.reveal_deadline = now + self.reveal_window;
+        }
+        Ok(())
+    }
+
+    /// Tallies a revealed vote, resolving the round once every staker has
+    /// revealed.
+    pub fn reveal(
+        &mut self,
+        credential_key: Pubkey,
+        verifier: &mut Verifier,
+        is_authentic: bool,
+        salt: &[u8; 32],
+        now: i64,
+    ) -> Result<()> {
+        require!(!self.is_finalized, VerifierError::AlreadyFinalized);
+        require!(!self.is_cancelled, VerifierError::Cancelled);
+        require!(
+            verifier.credential == credential_key,
+            VerifierError::NotStaked
+        );
+        require!(verifier.has_committed, VerifierError::NotCommitted);
+        require!(!verifier.has_voted, VerifierError::AlreadyVoted);
+        require!(
+            self.commit_count == self.quorum || now > self.voting_deadline,
+            VerifierError::RevealNotOpen
+        );
+        require!(
+            now <= self.reveal_deadline,
+            VerifierError::RevealPeriodEnded
+        );
+        require!(
+            vote_commitment(&credential_key, &verifier.authority, is_authentic, salt)
+                == verifier.commitment,
+            VerifierError::CommitmentMismatch
+        );
+
+        let forfeit = self.winners_cut(self.penalty_for(true, verifier.stake));
+        self.vote_count += 1;
+        self.revealed_weight += verifier.weight;
+        self.revealed_stake += verifier.stake;
+        self.revealed_forfeit += forfeit;
+        if is_authentic {
+            self.authentic_votes += 1;
+            self.authentic_weight += verifier.weight;
+            self.authentic_stake += verifier.stake;
+            self.authentic_forfeit += forfeit;
+        }
+
+        verifier.voted_authentic = is_authentic;
+        verifier.has_voted = true;
+
+        if self.vote_count == self.quorum {
+            self.resolve(now);
+        }
+        Ok(())
+    }
+
+    pub fn finalize_expired(&mut self, now: i64) -> Result<()> {
+        require!(!self.is_finalized, VerifierError::AlreadyFinalized);
+        require!(!self.is_cancelled, VerifierError::Cancelled);
+        require!(self.voting_deadline != 0, VerifierError::VotingNotOpen);
+        require!(now > self.reveal_deadline, VerifierError::VotingStillOpen);
+
+        self.resolve(now);
+        Ok(())
+    }
+
+    /// Marks a winner as paid and returns its share of the reward pool.
+    pub fn claim(&mut self, verifier: &mut Verifier, now: i64) -> Result<u64> {
+        require!(self.is_finalized, VerifierError::NotFinalized);
+        require!(self.payouts_open(now), VerifierError::PayoutsLocked);
+        require!(now <= self.claim_deadline, VerifierError::ClaimPeriodEnded);
+        require!(!verifier.has_claimed, VerifierError::AlreadyClaimed);
+
+        require!(verifier.has_voted, VerifierError::NotVoted);
+        require!(self.is_winner(verifier), VerifierError::NotInMajority);
+
+        let payout = reward_share(
+            self.reward_pool,
+            self.remaining_pool,
+            verifier.stake,
+            self.winning_stake(),
+            self.winner_count(),
+            self.claimed_count,
+        )?;
+        self.claimed_count += 1;
+        self.remaining_pool -= payout;
+        verifier.has_claimed = true;
+        Ok(payout)
+    }
+
+    /// Marks a minority or non-voting verifier as slashed and returns how its
+    /// stake is split.
+    pub fn slash(&mut self, verifier: &mut Verifier, now: i64) -> Result<Slash> {
+        require!(self.is_finalized, VerifierError::NotFinalized);
+        require!(self.payouts_open(now), VerifierError::PayoutsLocked);
+        require!(!verifier.has_claimed, VerifierError::AlreadyClaimed);
+        require!(
+            !verifier.has_voted
+                || (self.outcome != Outcome::Tie
+                    && verifier.voted_authentic != self.majority_authentic()),
+            VerifierError::NotSlashable
+        );
+
+        let penalty = self.penalty_for(verifier.has_committed, verifier.stake);
+        let (to_treasury, to_winners) = self.split_penalty(penalty);
+        self.slashed_count += 1;
+        verifier.has_claimed = true;
+        Ok(Slash {
+            penalty,
+            to_treasury,
+            to_winners,
+            refund: verifier.stake - penalty,
+        })
+    }
+
+    pub fn cancel(&mut self, now: i64) -> Result<()> {
+        require!(!self.is_finalized, VerifierError::AlreadyFinalized);
+        require!(!self.is_cancelled, VerifierError::Cancelled);
+        require!(
+            now > self.staking_deadline,
+            VerifierError::TimeoutNotReached
+        );
+        require!(
+            self.staker_count < self.quorum,
+            VerifierError::EnoughVerifiers
+        );
+
+        self.is_cancelled = true;
+        Ok(())
+    }
+
+    /// Marks a staker of a cancelled round as refunded and returns its stake.
+    pub fn refund(&mut self, credential_key: Pubkey, verifier: &mut Verifier) -> Result<u64> {
+        require!(self.is_cancelled, VerifierError::NotCancelled);
+        require!(!verifier.has_claimed, VerifierError::AlreadyClaimed);
+        require!(
+            verifier.credential == credential_key,
+            VerifierError::NotStaked
+        );
+
+        verifier.has_claimed = true;
+        self.remaining_pool -= verifier.stake;
+        self.refunded_count += 1;
+        Ok(verifier.stake)
+    }
+
+    /// Consistency checks between the round's counters and pools, run at the
+    /// end of every instruction that mutates a `Credential`.
+    pub fn assert_invariants(&self) -> Result<()> {
+        let counters_consistent = self.authentic_votes <= self.vote_count
+            && self.vote_count <= self.commit_count
+            && self.commit_count <= self.staker_count
+            && self.staker_count <= self.quorum
+            && self.quorum <= MAX_QUORUM;
+        let sums_consistent = self.authentic_weight <= self.revealed_weight
+            && self.authentic_stake <= self.revealed_stake
+            && self.revealed_stake <= self.total_staked
+            && self.authentic_forfeit <= self.revealed_forfeit
+            && self.revealed_forfeit <= self.committed_forfeit
+            && self.committed_abstain_forfeit <= self.abstain_forfeit;
+        let states_consistent = !(self.is_finalized && self.is_cancelled)
+            && (!self.is_settled || (self.is_finalized && !self.is_superseded))
+            && (self.is_finalized == (self.outcome != Outcome::Undecided))
+            && (self.refunded_count == 0 || self.is_cancelled)
+            && self.refunded_count <= self.staker_count;
+        // Winner counts are derived from the vote counters, so they are only
+        // looked at once the counters are known to be ordered.
+        let pool_consistent = counters_consistent
+            && if self.is_finalized {
+                let winners = self.winner_count();
+                self.reward_pool <= self.total_staked
+                    && self.remaining_pool <= self.reward_pool
+                    && self.claimed_count <= winners
+                    && self
+                        .staker_count
+                        .checked_sub(winners)
+                        .is_some_and(|losers| self.slashed_count <= losers)
+            } else if self.is_cancelled {
+                self.remaining_pool <= self.total_staked
+                    && self.claimed_count == 0
+                    && self.slashed_count == 0
+            } else {
+                self.remaining_pool == self.total_staked
+                    && self.claimed_count == 0
+                    && self.slashed_count == 0
+            };
+
+        require!(
+            counters_consistent && sums_consistent && states_consistent && pool_consistent,
+            VerifierError::InvariantViolation
+        );
+        Ok(())
+    }
+
+    pub fn majority_authentic(&self) -> bool {
+        self.outcome == Outcome::Authentic
+    }
+
+    /// Voters who share the pool: the majority, or every voter after a tie.
+    pub fn winner_count(&self) -> u32 {
+        match self.outcome {
+            Outcome::Authentic => self.authentic_votes,
+            Outcome::NotAuthentic => self.vote_count - self.authentic_votes,
+            Outcome::Tie | Outcome::Undecided => self.vote_count,
+        }
+    }
+
+    /// Whether claims and slashes may proceed: the appeal window has passed
+    /// and any appeal has been resolved.
+    pub fn payouts_open(&self, now: i64) -> bool {
+        self.is_finalized
+            && (self.appeal_window == 0 || now > self.appeal_deadline)
+            && (self.appeal == Pubkey::default() || self.appeal_resolved)
+    }
+
+    pub fn is_winner(&self, verifier: &Verifier) -> bool {
+        verifier.has_voted
+            && (self.outcome == Outcome::Tie
+                || verifier.voted_authentic == self.majority_authentic())
+    }
+
+    /// Settlement reads the majority's `Verifier` accounts, so they must stay
+    /// open until the outcome is written back or superseded, whether by an
+    /// appeal or by a change to the target.
+    pub fn needs_verifier_records(&self) -> bool {
+        self.is_finalized && self.outcome != Outcome::Tie && !self.is_settled && !self.is_superseded
+    }
+
+    pub fn verifier_is_terminal(&self, verifier: &Verifier, now: i64) -> bool {
+        if self.needs_verifier_records() {
+            return false;
+        }
+        verifier.has_claimed
+            || (self.is_winner(verifier) && self.payouts_open(now) && now > self.claim_deadline)
+    }
+
+    /// Whether every verifier has been paid, slashed or refunded, so the round
+    /// can be closed. Winners who miss the claim deadline do not block it.
+    pub fn is_closable(&self, now: i64) -> bool {
+        if !self.is_finalized {
+            return self.is_cancelled
+                && self.appeal_bond == 0
+                && self.refunded_count == self.staker_count;
+        }
+        let winners = self.winner_count();
+        self.payouts_open(now)
+            && self.appeal_bond == 0
+            && (self.is_settled || self.outcome == Outcome::Tie || self.is_superseded)
+            && self.slashed_count == self.staker_count - winners
+            && (self.claimed_count == winners || now > self.claim_deadline)
+    }
+
+    /// Unfreezes first-round payouts once its appeal is resolved. Payouts stay
+    /// locked until the appeal window has passed, so the claim window is
+    /// counted from whichever comes last.
+    pub fn resolve_appeal(&mut self, now: i64) {
+        self.appeal_resolved = true;
+        self.claim_deadline = now.max(self.appeal_deadline) + self.claim_window;
+    }
+
+    /// Flips a decisive outcome after a successful appeal, so the first-round
+    /// majority becomes the slashable minority.
+    pub fn overturn(&mut self) {
+        self.outcome = match self.outcome {
+            Outcome::Authentic => Outcome::NotAuthentic,
+            Outcome::NotAuthentic => Outcome::Authentic,
+            ref other => other.clone(),
+        };
+        self.overturned = true;
+        self.reward_pool = self.compute_reward_pool();
+        self.remaining_pool = self.reward_pool;
+    }
+
+    /// Combined stake of the voters who share the pool.
+    pub fn winning_stake(&self) -> u64 {
+        match self.outcome {
+            Outcome::Authentic => self.authentic_stake,
+            Outcome::NotAuthentic => self.revealed_stake - self.authentic_stake,
+            Outcome::Tie | Outcome::Undecided => self.revealed_stake,
+        }
+    }
+
+    pub fn vote_weight(&self, stake: u64, reputation_score: u32) -> Result<u64> {
+        Ok(match self.voting_mode {
+            VotingMode::Equal => 1,
+            VotingMode::StakeWeighted => stake,
+            VotingMode::ReputationWeighted => {
+                let multiplier = u64::from(reputation_score)
+                    .saturating_add(1)
+                    .min(MAX_REPUTATION_MULTIPLIER);
+                stake
+                    .checked_mul(multiplier)
+                    .ok_or(VerifierError::MathOverflow)?
+            }
+        })
+    }
+
+    /// Tallies the votes cast so far. A full-quorum tie under
+    /// `TiePolicy::Extend` reopens staking for one more verifier instead of
+    /// finalizing, falling back to a plain tie once `MAX_QUORUM` is reached.
+    pub fn resolve(&mut self, now: i64) {
+        self.outcome = tally(
+            self.authentic_weight,
+            self.revealed_weight,
+            self.majority_bps,
+        );
+        if self.outcome == Outcome::Tie
+            && self.tie_policy == TiePolicy::Extend
+            && self.vote_count == self.quorum
+            && self.quorum < MAX_QUORUM
+        {
+            self.quorum += 1;
+            self.outcome = Outcome::Undecided;
+            self.staking_deadline = now + self.staking_window;
+            self.voting_deadline = 0;
+            self.reveal_deadline = 0;
+            return;
+        }
+
+        self.is_finalized = true;
+        self.appeal_deadline = now + self.appeal_window;
+        self.claim_deadline = self.appeal_deadline + self.claim_window;
+        self.reward_pool = self.compute_reward_pool();
+        self.remaining_pool = self.reward_pool;
+    }
+
+    /// Winners' stakes plus their cut of every penalty. Penalties are rounded
+    /// per verifier, so the pool is built from the per-verifier sums to match
+    /// exactly what `slash_verifier` pays out.
+    fn compute_reward_pool(&self) -> u64 {
+        if self.winner_count() == 0 {
+            return 0;
+        }
+        let minority_forfeit = match self.outcome {
+            Outcome::Authentic => self.revealed_forfeit - self.authentic_forfeit,
+            Outcome::NotAuthentic => self.authentic_forfeit,
+            Outcome::Tie | Outcome::Undecided => 0,
+        };
+        let unrevealed_forfeit = self.committed_forfeit - self.revealed_forfeit;
+        self.winning_stake()
+            + minority_forfeit
+            + unrevealed_forfeit
+            + (self.abstain_forfeit - self.committed_abstain_forfeit)
+    }
+
+    /// Stake a losing verifier forfeits: all of it once it committed, whether
+    /// it then voted with the minority or withheld its reveal, and the
+    /// configured rate if it never committed.
+    pub fn penalty_for(&self, committed: bool, stake: u64) -> u64 {
+        if committed {
+            stake
+        } else {
+            bps_of(stake, self.non_voter_slash_bps)
+        }
+    }
+
+    /// Part of a penalty that goes to the winners when there are any.
+    pub fn winners_cut(&self, penalty: u64) -> u64 {
+        penalty - bps_of(penalty, self.treasury_share_bps)
+    }
+
+    /// Splits a penalty into the insurance pool's cut and the winners' cut.
+    /// Without winners the whole penalty goes to the insurance pool.
+    pub fn split_penalty(&self, penalty: u64) -> (u64, u64) {
+        if self.winner_count() == 0 {
+            return (penalty, 0);
+        }
+        let to_winners = self.winners_cut(penalty);
+        (penalty - to_winners, to_winners)
+    }
+}
+
+fn bps_of(amount: u64, bps: u16) -> u64 {
+    (u128::from(amount) * u128::from(bps) / u128::from(BPS_DENOMINATOR)) as u64
+}
+
+fn validate_slash_rates(treasury_share_bps: u16, non_voter_slash_bps: u16) -> Result<()> {
+    require!(
+        u64::from(treasury_share_bps) <= BPS_DENOMINATOR
+            && u64::from(non_voter_slash_bps) <= BPS_DENOMINATOR,
+        VerifierError::InvalidSlashRate
+    );
+    Ok(())
+}
+
+/// Announces a round that has just finalized on a tie under
+/// `TiePolicy::Escalate`.
+fn emit_if_escalated(credential: &Account<Credential>) {
+    if credential.is_finalized
+        && credential.outcome == Outcome::Tie
+        && credential.tie_policy == TiePolicy::Escalate
+    {
+        emit!(VerificationEscalated {
+            credential: credential.key(),
+            target: credential.target,
+            authentic_votes: credential.authentic_votes,
+            votes: credential.vote_count,
+        });
+    }
+}
+
+/// Outcome of ballots totalling `weight`, of which `authentic_weight` was
+/// authentic, where the winning side needs at least `majority_bps` of it.
+pub fn tally(authentic_weight: u64, weight: u64, majority_bps: u16) -> Outcome {
+    let threshold = u128::from(weight) * u128::from(majority_bps);
+    let bps = u128::from(BPS_DENOMINATOR);
+    if weight == 0 {
+        Outcome::Tie
+    } else if u128::from(authentic_weight) * bps >= threshold {
+        Outcome::Authentic
+    } else if u128::from(weight - authentic_weight) * bps >= threshold {
+        Outcome::NotAuthentic
+    } else {
+        Outcome::Tie
+    }
+}
+
+/// Pays `amount` lamports out of the credential's vault without taking it
+/// below rent exemption.
+fn pay_out<'info>(
+    credential: &Account<'info, Credential>,
+    vault: &SystemAccount<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let remaining_lamports = vault
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(VerifierError::InsufficientPool)?;
+    require!(
+        remaining_lamports >= Rent::get()?.minimum_balance(0),
+        VerifierError::InsufficientPool
+    );
+
+    transfer_from_vault(credential, vault, to, system_program, amount)
+}
+
+fn transfer_from_vault<'info>(
+    credential: &Account<'info, Credential>,
+    vault: &SystemAccount<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    let credential_key = credential.key();
+    let signer_seeds: &[&[&[u8]]] =
+        &[&[b"vault", credential_key.as_ref(), &[credential.vault_bump]]];
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: vault.to_account_info(),
+                to: to.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+/// Share of `total_pool` owed to a winner who staked `stake` out of the
+/// winners' combined `winning_stake`. The last claimant receives whatever
+/// remains, so rounding dust is never stranded and the sum of all shares
+/// equals the pool exactly.
+pub fn reward_share(
+    total_pool: u64,
+    remaining_pool: u64,
+    stake: u64,
+    winning_stake: u64,
+    winner_count: u32,
+    claimed_count: u32,
+) -> Result<u64> {
+    require!(
+        claimed_count < winner_count,
+        VerifierError::AllRewardsClaimed
+    );
+    if claimed_count + 1 == winner_count {
+        return Ok(remaining_pool);
+    }
+
+    let share = (u128::from(total_pool) * u128::from(stake))
+        .checked_div(u128::from(winning_stake))
+        .ok_or(VerifierError::MathOverflow)?;
+    Ok(u64::try_from(share)
+        .map_err(|_| VerifierError::MathOverflow)?
+        .min(remaining_pool))
+}
+
+/// How a slashed verifier's stake is split.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Slash {
+    pub penalty: u64,
+    pub to_treasury: u64,
+    pub to_winners: u64,
+    /// Unslashed part of the stake, returned to the verifier.
+    pub refund: u64,
 }
 
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct Verifier {
     pub credential: Pubkey,
+    /// `Credential::round_id` of the round this stake belongs to.
+    pub round_id: u64,
     pub authority: Pubkey,
+    pub stake: u64,
+    /// Weight of this verifier's vote under the credential's `VotingMode`.
+    pub weight: u64,
     pub voted_authentic: bool,
+    /// Set when the vote is revealed.
     pub has_voted: bool,
     pub has_claimed: bool,
+    pub commitment: [u8; 32],
+    pub has_committed: bool,
+}
+
+/// Hash a verifier commits to: `sha256(credential || authority || vote || salt)`.
+/// Binding the keys stops one verifier from replaying another's commitment.
+pub fn vote_commitment(
+    credential: &Pubkey,
+    authority: &Pubkey,
+    is_authentic: bool,
+    salt: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[
+        credential.as_ref(),
+        authority.as_ref(),
+        &[u8::from(is_authentic)],
+        salt,
+    ])
+    .to_bytes()
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolConfig {
+    pub admin: Pubkey,
+    pub treasury_share_bps: u16,
+    pub non_voter_slash_bps: u16,
+    pub eligibility: EligibilityRule,
+    /// Whether stakers must also belong to the credential's verifier pool.
+    pub require_pool: bool,
+    /// Lamports an appellant posts to open an appeal round.
+    pub appeal_bond: u64,
+    pub round_limits: RoundLimits,
+    /// Rounds opened so far; the next round takes this as its `round_id`.
+    pub rounds_opened: u64,
+    pub bump: u8,
+}
+
+/// Bounds on the `RoundParams` anyone opening a round may choose. The floors
+/// stop sybil rounds with a single cheap verifier, and the window cap keeps a
+/// round from holding its target hostage.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct RoundLimits {
+    pub min_quorum: u32,
+    /// Lowest `min_stake` a round may ask for, in lamports.
+    pub min_stake: u64,
+    /// Every window must lie within `[min_window, max_window]` seconds; the
+    /// appeal window may also be zero.
+    pub min_window: i64,
+    pub max_window: i64,
+}
+
+impl RoundLimits {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (1..=MAX_QUORUM).contains(&self.min_quorum)
+                && self.min_stake > 0
+                && self.min_window > 0
+                && self.min_window <= self.max_window,
+            VerifierError::InvalidRoundLimits
+        );
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistEntry {
+    pub verifier: Pubkey,
+    pub bump: u8,
+}
+
+/// Membership of a domain-expert pool, keyed by credential type and tag.
+#[account]
+#[derive(InitSpace)]
+pub struct PoolMembership {
+    pub credential_type: CredentialType,
+    #[max_len(MAX_POOL_TAG_LEN)]
+    pub tag: String,
+    pub member: Pubkey,
+    pub bump: u8,
+}
+
+pub fn pool_type_seed(credential_type: &CredentialType) -> [u8; 1] {
+    [credential_type.clone() as u8]
+}
+
+/// Per-wallet track record across every round the wallet has staked in.
+#[account]
+#[derive(InitSpace)]
+pub struct VerifierReputation {
+    pub authority: Pubkey,
+    pub registered_at: i64,
+    /// Revealed votes in finalized rounds.
+    pub votes: u32,
+    /// Votes that matched a decisive outcome.
+    pub majority_hits: u32,
+    pub slashes: u32,
+    /// Rewards received on top of returned stakes.
+    pub lifetime_earnings: u64,
+    pub bump: u8,
+}
+
+impl VerifierReputation {
+    pub fn init_if_new(&mut self, authority: Pubkey, now: i64, bump: u8) {
+        if self.authority == Pubkey::default() {
+            self.authority = authority;
+            self.registered_at = now;
+            self.bump = bump;
+        }
+    }
+
+    /// Majority hits net of slashes.
+    pub fn score(&self) -> u32 {
+        self.majority_hits.saturating_sub(self.slashes)
+    }
+}
+
+/// Extra requirement a wallet must meet before it may stake.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub enum EligibilityRule {
+    Open,
+    /// The wallet must have an `AllowlistEntry`.
+    Allowlist,
+    /// The wallet's `VerifierReputation` must be at least this many seconds old.
+    MinAccountAge {
+        seconds: i64,
+    },
+}
+
+/// Checks the eligibility rule and, when the round requires it, membership of
+/// the credential's verifier pool; the two are enforced independently.
+fn check_eligibility(
+    rule: &EligibilityRule,
+    require_pool: bool,
+    is_allowlisted: bool,
+    is_pool_member: bool,
+    reputation: &VerifierReputation,
+    now: i64,
+) -> Result<()> {
+    match rule {
+        EligibilityRule::Open => {}
+        EligibilityRule::Allowlist => {
+            require!(is_allowlisted, VerifierError::NotEligible);
+        }
+        EligibilityRule::MinAccountAge { seconds } => {
+            require!(
+                now - reputation.registered_at >= *seconds,
+                VerifierError::NotEligible
+            );
+        }
+    }
+    require!(!require_pool || is_pool_member, VerifierError::NotInPool);
+    Ok(())
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct InsurancePool {
+    pub total_received: u64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RoundParams {
+    pub voting_mode: VotingMode,
+    /// Stake bounds; `Equal` voting requires them to be the same.
+    pub min_stake: u64,
+    pub max_stake: u64,
+    pub quorum: u32,
+    pub majority_bps: u16,
+    pub tie_policy: TiePolicy,
+    /// Seconds, from creation, during which verifiers may stake.
+    pub staking_window: i64,
+    /// Seconds, from the last stake, during which stakers may commit votes.
+    pub voting_window: i64,
+    /// Seconds, after the commit phase, during which votes may be revealed.
+    pub reveal_window: i64,
+    /// Seconds, from finalization, during which rewards may be claimed.
+    pub claim_window: i64,
+    /// Minimum `VerifierReputation::score` a wallet needs to stake; zero for none.
+    pub min_reputation: u32,
+    /// Verifier pool tag, empty for the type-wide pool.
+    pub pool_tag: String,
+    /// Seconds after finalization during which the outcome may be appealed;
+    /// zero to disallow appeals. Ignored for appeal rounds.
+    pub appeal_window: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub enum VotingMode {
+    /// Every verifier stakes the same amount and casts one vote.
+    #[default]
+    Equal,
+    /// Votes are weighted by stake.
+    StakeWeighted,
+    /// Votes are weighted by stake times a reputation multiplier of
+    /// `1 + score`, capped at `MAX_REPUTATION_MULTIPLIER`.
+    ReputationWeighted,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub enum TiePolicy {
+    /// Refund every voter's stake.
+    #[default]
+    RefundAll,
+    /// Open another verifier slot and keep voting.
+    Extend,
+    /// Refund stakes and hand the decision to tablu's authorized verifiers.
+    Escalate,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub enum Outcome {
+    #[default]
+    Undecided,
+    Authentic,
+    NotAuthentic,
+    Tie,
+}
+
+#[event]
+pub struct VerificationEscalated {
+    pub credential: Pubkey,
+    pub target: Pubkey,
+    pub authentic_votes: u32,
+    pub votes: u32,
+}
+
+#[event]
+pub struct SlashEvent {
+    pub credential: Pubkey,
+    pub verifier: Pubkey,
+    pub voted: bool,
+    pub amount: u64,
+    pub to_treasury: u64,
+    pub to_winners: u64,
+}
+
+#[event]
+pub struct RoundSuperseded {
+    pub credential: Pubkey,
+    pub target: Pubkey,
+}
+
+#[event]
+pub struct AppealOpened {
+    pub original: Pubkey,
+    pub appeal: Pubkey,
+    pub appellant: Pubkey,
+    pub bond: u64,
+}
+
+#[event]
+pub struct AppealResolved {
+    pub original: Pubkey,
+    pub appeal: Pubkey,
+    pub overturned: bool,
 }
 
 #[error_code]
@@ -288,4 +2334,719 @@ pub enum VerifierError {
     StakingPeriodEnded,
     #[msg("Maximum number of verifiers reached")]
     MaxVerifiersReached,
-}
\ No newline at end of file
+    #[msg("Verification outcome has already been settled")]
+    AlreadySettled,
+    #[msg("Verifier did not vote with the majority")]
+    NotInMajority,
+    #[msg("Verifier was passed more than once")]
+    DuplicateVoter,
+    #[msg("Every majority verifier must be provided")]
+    IncompleteMajority,
+    #[msg("Account does not match the credential being verified")]
+    TargetMismatch,
+    #[msg("Every majority verifier has already been paid")]
+    AllRewardsClaimed,
+    #[msg("Reward pool cannot cover this payout")]
+    InsufficientPool,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Quorum must be between the protocol minimum and the maximum number of verifiers")]
+    InvalidQuorum,
+    #[msg("Majority must be above 50% and at most 100%")]
+    InvalidMajority,
+    #[msg("Verification ended in a tie")]
+    NoMajority,
+    #[msg("Round windows must lie within the protocol's limits")]
+    InvalidWindow,
+    #[msg("Voting opens once every staking slot is filled")]
+    VotingNotOpen,
+    #[msg("Voting period has ended")]
+    VotingPeriodEnded,
+    #[msg("Voting period has not ended yet")]
+    VotingStillOpen,
+    #[msg("Claim period has ended")]
+    ClaimPeriodEnded,
+    #[msg("Slashing rates must be at most 100%")]
+    InvalidSlashRate,
+    #[msg("Only the config admin can perform this action")]
+    Unauthorized,
+    #[msg("Verifier voted with the winning side")]
+    NotSlashable,
+    #[msg("Verifier has not committed a vote")]
+    NotCommitted,
+    #[msg("Votes can be revealed once every staker has committed or the commit phase has ended")]
+    RevealNotOpen,
+    #[msg("Reveal period has ended")]
+    RevealPeriodEnded,
+    #[msg("Revealed vote does not match the commitment")]
+    CommitmentMismatch,
+    #[msg("Target credential is not pending verification")]
+    TargetNotPending,
+    #[msg("Target credential was edited after the round opened")]
+    TargetChanged,
+    #[msg("Credential owners cannot verify their own credentials")]
+    SelfVerification,
+    #[msg("Wallet does not meet the staking eligibility rule")]
+    NotEligible,
+    #[msg("Verifier reputation is below the credential's minimum")]
+    ReputationTooLow,
+    #[msg("Stake range must meet the protocol minimum, be ordered, and be fixed for equal voting")]
+    InvalidStakeRange,
+    #[msg("Stake is outside the credential's allowed range")]
+    InvalidStakeAmount,
+    #[msg("Pool tag is too long")]
+    PoolTagTooLong,
+    #[msg("Appeal window has closed")]
+    AppealWindowClosed,
+    #[msg("Appeal rounds cannot be appealed again")]
+    NotAppealable,
+    #[msg("Appeal round must have a larger quorum than the original")]
+    AppealTooSmall,
+    #[msg("Appeal has already been resolved")]
+    AppealAlreadyResolved,
+    #[msg("Round's appeal has not been resolved yet")]
+    AppealUnresolved,
+    #[msg("Payouts are locked until the appeal window passes or the appeal is resolved")]
+    PayoutsLocked,
+    #[msg("Every verifier must be paid, slashed or refunded before closing")]
+    NotClosable,
+    #[msg("Verifier account is still needed to settle the round")]
+    VerifierStillNeeded,
+    #[msg("Verifier can still claim, be slashed or be refunded")]
+    VerifierNotTerminal,
+    #[msg("Credential verification has been cancelled")]
+    Cancelled,
+    #[msg("Stakes can only be refunded from a cancelled round")]
+    NotCancelled,
+    #[msg("Credential accounting invariant violated")]
+    InvariantViolation,
+    #[msg("Verifier belongs to an earlier round of this credential")]
+    StaleVerifier,
+    #[msg("Round's outcome was superseded and will not be settled")]
+    RoundSuperseded,
+    #[msg("Target credential has not changed since the round opened")]
+    TargetUnchanged,
+    #[msg("Appeal round must be at least as strict as the original")]
+    AppealTooLenient,
+    #[msg("Wallet is not a member of the credential's verifier pool")]
+    NotInPool,
+    #[msg("Round limits must have a valid quorum, a positive stake and an ordered window range")]
+    InvalidRoundLimits,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Small deterministic generator, so the randomized tests need no extra
+    /// dependencies and every failure reproduces from its seed.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: u64) -> u64 {
+            self.next() % n
+        }
+
+        fn range(&mut self, lo: u64, hi: u64) -> u64 {
+            lo + self.below(hi - lo + 1)
+        }
+
+        fn chance(&mut self, percent: u64) -> bool {
+            self.below(100) < percent
+        }
+
+        /// Mostly boundary values, which is where counter arithmetic breaks.
+        fn edge_u32(&mut self) -> u32 {
+            match self.below(6) {
+                0 => 0,
+                1 => 1,
+                2 => u32::MAX,
+                3 => u32::MAX - 1,
+                _ => self.below(u64::from(MAX_QUORUM) + 2) as u32,
+            }
+        }
+
+        fn edge_u64(&mut self) -> u64 {
+            match self.below(5) {
+                0 => 0,
+                1 => u64::MAX,
+                2 => self.next(),
+                _ => self.below(1_000),
+            }
+        }
+    }
+
+    const LIMITS: RoundLimits = RoundLimits {
+        min_quorum: 1,
+        min_stake: 1,
+        min_window: 1,
+        max_window: 1_000,
+    };
+
+    fn zeroed<T: AnchorDeserialize>(space: usize) -> T {
+        T::deserialize(&mut &vec![0u8; space][..]).unwrap()
+    }
+
+    fn config(treasury_share_bps: u16, non_voter_slash_bps: u16) -> ProtocolConfig {
+        let mut config: ProtocolConfig = zeroed(ProtocolConfig::INIT_SPACE);
+        config.treasury_share_bps = treasury_share_bps;
+        config.non_voter_slash_bps = non_voter_slash_bps;
+        config.round_limits = LIMITS;
+        config
+    }
+
+    fn params(quorum: u32, min_stake: u64, max_stake: u64) -> RoundParams {
+        RoundParams {
+            voting_mode: VotingMode::StakeWeighted,
+            min_stake,
+            max_stake,
+            quorum,
+            majority_bps: 5_001,
+            tie_policy: TiePolicy::RefundAll,
+            staking_window: 100,
+            voting_window: 100,
+            reveal_window: 100,
+            claim_window: 100,
+            min_reputation: 0,
+            pool_tag: String::new(),
+            appeal_window: 0,
+        }
+    }
+
+    struct Staker {
+        verifier: Verifier,
+        authority: Pubkey,
+        vote: bool,
+        salt: [u8; 32],
+    }
+
+    /// A round driven through the same transitions as the instructions, with
+    /// the vault's balance above its rent floor tracked alongside. A failed
+    /// transition leaves everything untouched, as a failed transaction would.
+    struct Round {
+        key: Pubkey,
+        credential: Credential,
+        stakers: Vec<Staker>,
+        vault: u64,
+        now: i64,
+    }
+
+    impl Round {
+        fn open(params: RoundParams, config: &mut ProtocolConfig) -> Self {
+            let mut credential: Credential = zeroed(Credential::INIT_SPACE);
+            credential.target_owner = Pubkey::new_unique();
+            credential.appeal_window = params.appeal_window;
+            credential.open_round(params, config, 0).unwrap();
+            Round {
+                key: Pubkey::new_unique(),
+                credential,
+                stakers: Vec::new(),
+                vault: 0,
+                now: 0,
+            }
+        }
+
+        fn apply<T>(
+            &mut self,
+            transition: impl FnOnce(&mut Credential, &mut Vec<Staker>, i64) -> Result<T>,
+        ) -> Result<T> {
+            let mut credential = self.credential.clone();
+            let mut stakers: Vec<Staker> = self
+                .stakers
+                .iter()
+                .map(|staker| Staker {
+                    verifier: staker.verifier.clone(),
+                    ..*staker
+                })
+                .collect();
+            let result = transition(&mut credential, &mut stakers, self.now)?;
+            credential.assert_invariants().unwrap();
+            self.credential = credential;
+            self.stakers = stakers;
+            Ok(result)
+        }
+
+        fn withdraw(&mut self, amount: u64) {
+            self.vault = self.vault.checked_sub(amount).expect("vault overdrawn");
+        }
+
+        fn stake(&mut self, amount: u64, vote: bool, salt: [u8; 32]) -> Result<()> {
+            let key = self.key;
+            self.apply(|credential, stakers, now| {
+                let mut staker = Staker {
+                    verifier: Verifier::default(),
+                    authority: Pubkey::new_unique(),
+                    vote,
+                    salt,
+                };
+                credential.add_stake(
+                    key,
+                    &mut staker.verifier,
+                    staker.authority,
+                    amount,
+                    0,
+                    now,
+                )?;
+                stakers.push(staker);
+                Ok(())
+            })?;
+            self.vault += amount;
+            Ok(())
+        }
+
+        fn commit(&mut self, index: usize) -> Result<()> {
+            let key = self.key;
+            self.apply(|credential, stakers, now| {
+                let staker = &mut stakers[index];
+                let commitment =
+                    vote_commitment(&key, &staker.authority, staker.vote, &staker.salt);
+                credential.commit(key, &mut staker.verifier, commitment, now)
+            })
+        }
+
+        fn reveal(&mut self, index: usize) -> Result<()> {
+            let key = self.key;
+            self.apply(|credential, stakers, now| {
+                let staker = &mut stakers[index];
+                credential.reveal(key, &mut staker.verifier, staker.vote, &staker.salt, now)
+            })
+        }
+
+        fn finalize(&mut self) -> Result<()> {
+            self.apply(|credential, _, now| credential.finalize_expired(now))
+        }
+
+        fn claim(&mut self, index: usize) -> Result<u64> {
+            let payout = self.apply(|credential, stakers, now| {
+                credential.claim(&mut stakers[index].verifier, now)
+            })?;
+            self.withdraw(payout);
+            Ok(payout)
+        }
+
+        fn slash(&mut self, index: usize) -> Result<Slash> {
+            let slash = self.apply(|credential, stakers, now| {
+                credential.slash(&mut stakers[index].verifier, now)
+            })?;
+            assert_eq!(slash.to_treasury + slash.to_winners, slash.penalty);
+            self.withdraw(slash.to_treasury + slash.refund);
+            Ok(slash)
+        }
+
+        fn cancel(&mut self) -> Result<()> {
+            self.apply(|credential, _, now| credential.cancel(now))
+        }
+
+        fn refund(&mut self, index: usize) -> Result<u64> {
+            let key = self.key;
+            let refund = self.apply(|credential, stakers, _| {
+                credential.refund(key, &mut stakers[index].verifier)
+            })?;
+            self.withdraw(refund);
+            Ok(refund)
+        }
+
+        /// Ends the round one way or the other and pays out every staker it
+        /// still can.
+        fn drain(&mut self) {
+            for _ in 0..=MAX_QUORUM {
+                if self.credential.is_finalized || self.credential.is_cancelled {
+                    break;
+                }
+                self.now = self
+                    .now
+                    .max(self.credential.staking_deadline)
+                    .max(self.credential.reveal_deadline)
+                    + 1;
+                let _ = self.cancel();
+                let _ = self.finalize();
+            }
+            if self.credential.is_finalized {
+                self.now = self.now.max(self.credential.appeal_deadline + 1);
+            }
+            for index in 0..self.stakers.len() {
+                let _ = self.claim(index);
+                let _ = self.slash(index);
+                let _ = self.refund(index);
+            }
+        }
+    }
+
+    fn random_round(rng: &mut Rng) -> Round {
+        let mut config = config(
+            rng.range(0, BPS_DENOMINATOR) as u16,
+            rng.range(0, BPS_DENOMINATOR) as u16,
+        );
+        let min_stake = rng.range(1, 1_000_000_000);
+        let mut params = params(
+            rng.range(1, u64::from(MAX_QUORUM)) as u32,
+            min_stake,
+            rng.range(min_stake, 2_000_000_000),
+        );
+        params.voting_mode = match rng.below(3) {
+            0 => {
+                params.max_stake = params.min_stake;
+                VotingMode::Equal
+            }
+            1 => VotingMode::StakeWeighted,
+            _ => VotingMode::ReputationWeighted,
+        };
+        params.majority_bps = rng.range(5_001, BPS_DENOMINATOR) as u16;
+        params.tie_policy = match rng.below(3) {
+            0 => TiePolicy::RefundAll,
+            1 => TiePolicy::Extend,
+            _ => TiePolicy::Escalate,
+        };
+        params.appeal_window = rng.range(0, 100) as i64;
+        Round::open(params, &mut config)
+    }
+
+    fn random_step(round: &mut Round, rng: &mut Rng) {
+        let index = match round.stakers.len() {
+            0 => None,
+            len => Some(rng.below(len as u64) as usize),
+        };
+        // Weighted towards staking and voting so most rounds get decided.
+        match (rng.below(100), index) {
+            (0..=24, _) => {
+                let credential = &round.credential;
+                let amount = if rng.chance(90) {
+                    rng.range(credential.min_stake, credential.max_stake)
+                } else {
+                    rng.edge_u64()
+                };
+                let _ = round.stake(amount, rng.chance(50), [rng.next() as u8; 32]);
+            }
+            (25..=44, Some(index)) => {
+                let _ = round.commit(index);
+            }
+            (45..=64, Some(index)) => {
+                let _ = round.reveal(index);
+            }
+            (65..=69, _) => {
+                let _ = round.finalize();
+            }
+            (70..=79, Some(index)) => {
+                let _ = round.claim(index);
+            }
+            (80..=87, Some(index)) => {
+                let _ = round.slash(index);
+            }
+            (88..=90, _) => {
+                let _ = round.cancel();
+            }
+            (91..=93, Some(index)) => {
+                let _ = round.refund(index);
+            }
+            _ => round.now += rng.range(0, 40) as i64,
+        }
+    }
+
+    #[test]
+    fn random_rounds_keep_invariants_and_never_overdraw_the_vault() {
+        for seed in 1..=500 {
+            let mut rng = Rng(seed);
+            let mut round = random_round(&mut rng);
+            for _ in 0..300 {
+                random_step(&mut round, &mut rng);
+            }
+            round.drain();
+
+            let credential = &round.credential;
+            if credential.is_cancelled {
+                assert_eq!(round.vault, 0, "seed {seed}");
+                assert_eq!(credential.refunded_count, credential.staker_count);
+            } else {
+                assert!(credential.is_finalized, "seed {seed}");
+                // Whatever the winners have not claimed is all that is left.
+                assert_eq!(round.vault, credential.remaining_pool, "seed {seed}");
+                if credential.claimed_count == credential.winner_count() {
+                    assert_eq!(round.vault, 0, "seed {seed}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn assert_invariants_never_panics_on_corrupted_state() {
+        for seed in 1..=5_000 {
+            let mut rng = Rng(seed);
+            let mut credential = random_round(&mut rng).credential;
+            credential.vote_count = rng.edge_u32();
+            credential.authentic_votes = rng.edge_u32();
+            credential.commit_count = rng.edge_u32();
+            credential.staker_count = rng.edge_u32();
+            credential.quorum = rng.edge_u32();
+            credential.claimed_count = rng.edge_u32();
+            credential.slashed_count = rng.edge_u32();
+            credential.refunded_count = rng.edge_u32();
+            credential.revealed_stake = rng.edge_u64();
+            credential.authentic_stake = rng.edge_u64();
+            credential.total_staked = rng.edge_u64();
+            credential.reward_pool = rng.edge_u64();
+            credential.remaining_pool = rng.edge_u64();
+            credential.is_finalized = rng.chance(50);
+            credential.is_cancelled = rng.chance(30);
+            credential.is_settled = rng.chance(20);
+            credential.is_superseded = rng.chance(20);
+            credential.outcome = match rng.below(4) {
+                0 => Outcome::Undecided,
+                1 => Outcome::Authentic,
+                2 => Outcome::NotAuthentic,
+                _ => Outcome::Tie,
+            };
+            let _ = credential.assert_invariants();
+        }
+    }
+
+    #[test]
+    fn assert_invariants_rejects_broken_counters() {
+        let mut round = Round::open(params(3, 10, 10), &mut config(0, 0));
+        for vote in [true, true, false] {
+            round.stake(10, vote, [1; 32]).unwrap();
+        }
+        for index in 0..3 {
+            round.commit(index).unwrap();
+        }
+        for index in 0..3 {
+            round.reveal(index).unwrap();
+        }
+        let finalized = round.credential;
+        assert!(finalized.is_finalized);
+        finalized.assert_invariants().unwrap();
+
+        let breaks: [fn(&mut Credential); 6] = [
+            |c| c.authentic_votes = c.vote_count + 1,
+            |c| c.vote_count = c.commit_count + 1,
+            |c| c.slashed_count = c.staker_count,
+            |c| c.claimed_count = c.winner_count() + 1,
+            |c| c.remaining_pool = c.reward_pool + 1,
+            |c| c.is_cancelled = true,
+        ];
+        for (case, corrupt) in breaks.iter().enumerate() {
+            let mut credential = finalized.clone();
+            corrupt(&mut credential);
+            assert!(credential.assert_invariants().is_err(), "case {case}");
+        }
+    }
+
+    #[test]
+    fn reward_shares_never_exceed_deposits() {
+        for seed in 1..=5_000 {
+            let mut rng = Rng(seed);
+            let max_stake = if rng.chance(20) {
+                u64::MAX / u64::from(MAX_QUORUM) / 2
+            } else {
+                rng.range(1, 1_000)
+            };
+            let winner_count = rng.range(1, u64::from(MAX_QUORUM)) as u32;
+            let stakes: Vec<u64> = (0..winner_count).map(|_| rng.range(1, max_stake)).collect();
+            let winning_stake: u64 = stakes.iter().sum();
+            let losing_stake = rng.range(0, max_stake);
+            let deposits = winning_stake + losing_stake;
+            // The pool is the winners' stakes plus whatever was slashed from
+            // the losers for them.
+            let pool = winning_stake + rng.range(0, losing_stake);
+
+            let mut order: Vec<usize> = (0..stakes.len()).collect();
+            for i in (1..order.len()).rev() {
+                order.swap(i, rng.below(i as u64 + 1) as usize);
+            }
+
+            let mut remaining = pool;
+            let mut paid = 0u64;
+            for (claimed_count, &winner) in order.iter().enumerate() {
+                let share = reward_share(
+                    pool,
+                    remaining,
+                    stakes[winner],
+                    winning_stake,
+                    winner_count,
+                    claimed_count as u32,
+                )
+                .unwrap();
+                let exact = u128::from(pool) * u128::from(stakes[winner]);
+                let floor = (exact / u128::from(winning_stake)) as u64;
+                if claimed_count + 1 < order.len() {
+                    assert_eq!(share, floor, "seed {seed}");
+                } else {
+                    // The last claimant picks up at most one lamport of
+                    // rounding dust per earlier claimant.
+                    assert!(share >= floor, "seed {seed}");
+                    assert!(share - floor < u64::from(winner_count), "seed {seed}");
+                }
+                remaining -= share;
+                paid += share;
+                assert!(paid <= deposits, "seed {seed}");
+            }
+            assert_eq!(paid, pool, "seed {seed}");
+            assert_eq!(remaining, 0, "seed {seed}");
+            assert!(reward_share(
+                pool,
+                remaining,
+                stakes[0],
+                winning_stake,
+                winner_count,
+                winner_count
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn early_appeal_resolution_keeps_the_full_claim_window() {
+        let mut params = params(1, 10, 10);
+        params.appeal_window = 500;
+        let mut round = Round::open(params, &mut config(1_000, 2_000));
+        round.stake(10, true, [1; 32]).unwrap();
+        round.commit(0).unwrap();
+        round.reveal(0).unwrap();
+        assert!(round.credential.is_finalized);
+        let appeal_deadline = round.credential.appeal_deadline;
+
+        // An appeal opens and resolves well inside the appeal window.
+        round.credential.appeal = Pubkey::new_unique();
+        round.now = 10;
+        round
+            .apply(|credential, _, now| {
+                credential.resolve_appeal(now);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(round.credential.claim_deadline, appeal_deadline + 100);
+
+        let error = |error: VerifierError| -> Error { error.into() };
+        assert_eq!(round.claim(0), Err(error(VerifierError::PayoutsLocked)));
+        round.now = appeal_deadline + 100;
+        assert_eq!(round.claim(0), Ok(10));
+    }
+
+    /// How far the first staker got before anyone tried to cancel the round.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Progress {
+        Staked,
+        Committed,
+        /// Revealed into a tie that extended the round by another slot.
+        Voted,
+        /// Revealed into a decided round.
+        Decided,
+    }
+
+    fn round_at(progress: Progress) -> Round {
+        let mut params = params(2, 10, 10);
+        params.tie_policy = TiePolicy::Extend;
+        let mut round = Round::open(params, &mut config(1_000, 2_000));
+        round.stake(10, true, [1; 32]).unwrap();
+        if progress == Progress::Staked {
+            return round;
+        }
+        round
+            .stake(10, progress != Progress::Voted, [2; 32])
+            .unwrap();
+        round.commit(0).unwrap();
+        round.commit(1).unwrap();
+        if progress == Progress::Committed {
+            return round;
+        }
+        round.reveal(0).unwrap();
+        round.reveal(1).unwrap();
+        assert_eq!(round.credential.is_finalized, progress == Progress::Decided);
+        round
+    }
+
+    /// Cancel, refund and claim transitions on `Credential` across round
+    /// progress, deadline expiry and an earlier successful claim.
+    #[test]
+    fn cancel_and_refund_transitions() {
+        let error = |error: VerifierError| -> Error { error.into() };
+        for progress in [
+            Progress::Staked,
+            Progress::Committed,
+            Progress::Voted,
+            Progress::Decided,
+        ] {
+            for expired in [false, true] {
+                // Only a decided round has anything to claim.
+                let claims: &[bool] = if progress == Progress::Decided {
+                    &[false, true]
+                } else {
+                    &[false]
+                };
+                for &claimed in claims {
+                    let case = format!("{progress:?} expired={expired} claimed={claimed}");
+                    let mut round = round_at(progress);
+                    if claimed {
+                        assert_eq!(round.claim(0), Ok(10), "{case}");
+                        assert_eq!(
+                            round.claim(0),
+                            Err(error(VerifierError::AlreadyClaimed)),
+                            "{case}"
+                        );
+                    }
+                    let vault = round.vault;
+                    if expired {
+                        round.now = round.credential.staking_deadline + 1;
+                    }
+
+                    let cancelled = round.cancel();
+                    match (progress, expired) {
+                        (Progress::Decided, _) => {
+                            assert_eq!(cancelled, Err(error(VerifierError::AlreadyFinalized)))
+                        }
+                        (_, false) => {
+                            assert_eq!(cancelled, Err(error(VerifierError::TimeoutNotReached)))
+                        }
+                        (Progress::Committed, true) => {
+                            assert_eq!(cancelled, Err(error(VerifierError::EnoughVerifiers)))
+                        }
+                        _ => assert_eq!(cancelled, Ok(()), "{case}"),
+                    }
+                    assert_eq!(round.credential.is_cancelled, cancelled.is_ok(), "{case}");
+
+                    if !round.credential.is_cancelled {
+                        // Stakes only leave a live or decided round through
+                        // claims and slashes.
+                        assert_eq!(
+                            round.refund(0),
+                            Err(error(VerifierError::NotCancelled)),
+                            "{case}"
+                        );
+                        assert_eq!(round.vault, vault, "{case}");
+                        continue;
+                    }
+
+                    // Every staker gets back exactly what it put in, once,
+                    // whether or not it voted.
+                    assert_eq!(round.refund(0), Ok(10), "{case}");
+                    assert_eq!(
+                        round.refund(0),
+                        Err(error(VerifierError::AlreadyClaimed)),
+                        "{case}"
+                    );
+                    for index in 1..round.stakers.len() {
+                        assert_eq!(round.refund(index), Ok(10), "{case}");
+                    }
+                    assert_eq!(round.vault, 0, "{case}");
+                    assert!(round.credential.is_closable(round.now), "{case}");
+                    assert_eq!(
+                        round.claim(0),
+                        Err(error(VerifierError::NotFinalized)),
+                        "{case}"
+                    );
+                    assert_eq!(
+                        round.slash(0),
+                        Err(error(VerifierError::NotFinalized)),
+                        "{case}"
+                    );
+                }
+            }
+        }
+    }
+}
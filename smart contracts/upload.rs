@@ -1,44 +1,61 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::pubkey::Pubkey;
 use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
 declare_id!("apwW9Vqxtu4Ga2dQ4R91jyYtWZ9HUFtx13MmPPfwLEb");
 
+pub const MAX_AUTHORIZED_VERIFIERS: usize = 16;
+pub const MAX_CREDENTIAL_VERIFIERS: usize = 16;
+pub const MAX_NAME_LEN: usize = 128;
+pub const MAX_DESCRIPTION_LEN: usize = 512;
+pub const MAX_LINK_LEN: usize = 256;
+pub const MAX_COLLABORATORS: usize = 10;
+pub const MAX_COLLABORATOR_LEN: usize = 64;
+pub const MIN_PASSOUT_YEAR: i64 = 1950;
+/// Degrees may be submitted ahead of graduation, up to this many years out.
+pub const MAX_FUTURE_PASSOUT_YEARS: i64 = 10;
+const SECONDS_PER_YEAR: i64 = 31_556_952;
+
 #[program]
 pub mod tablu {
     use super::*;
 
-    const UPLOAD_FEE: u64 = 5_500_449; 
-    pub const TREASURY_WALLET: &str = "C9KvY6JP9LNJo7vpJhkzVdtAVn6pLKuB52uhfLWCj4oU"; 
-
     pub fn submit_degree(
         ctx: Context<DegreeSubmitCredential>,
         degree_name: String,
         college_name: String,
         passout_year: i64,
+        expires_at: Option<i64>,
+        payment_method: PaymentMethod,
     ) -> Result<()> {
-        // Transfer 1 SOL to treasury
-        let cpi_context = CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.user.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
-            },
-        );
-        
-        system_program::transfer(cpi_context, UPLOAD_FEE)?;
+        ctx.accounts.fee.collect(
+            &ctx.accounts.user,
+            &ctx.accounts.system_program,
+            CredentialType::Degree,
+            payment_method,
+        )?;
+        let profile = &mut ctx.accounts.profile;
+        profile.init_if_new(*ctx.accounts.user.key, ctx.bumps.profile);
         msg!("Degree Name: {:?}", degree_name);
         msg!("College Name: {:?}", college_name);
         msg!("Passout Year: {:?}", passout_year);
 
+        let now = Clock::get()?.unix_timestamp;
         let credential = &mut ctx.accounts.credential;
         credential.user_address = *ctx.accounts.user.key;
         credential.degree_name = degree_name.clone();
         credential.college_name = college_name.clone();
         credential.passout_year = passout_year;
-        credential.timestamp = Clock::get()?.unix_timestamp;
+        credential.expires_at = expires_at;
+        credential.validate(now)?;
+        credential.timestamp = now;
         credential.verifiers = Vec::new();
         credential.status = VerificationStatus::Pending;
+        credential.index = profile.degree_count;
+        credential.bump = ctx.bumps.credential;
+        profile.degree_count += 1;
+        profile.last_submission_at = credential.timestamp;
 
         emit!(DegreeCredentialSubmitted {
             user: *ctx.accounts.user.key,
@@ -52,39 +69,33 @@ pub mod tablu {
     }
 pub fn submit_project(
     ctx: Context<ProjectSubmitCredential>,
-    project_name: String,
-    project_description: String,
-    collaborators: Option<Vec<String>>,
-    start_date: i32, 
-    end_date: Option<i32>, 
-    currently_working: Option<bool>,
-    project_link: String,
+    details: ProjectDetails,
+    payment_method: PaymentMethod,
 ) -> Result<()> {
-    let cpi_context = CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.user.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
-            },
-        );
-        
-    system_program::transfer(cpi_context, UPLOAD_FEE)?;
+    ctx.accounts.fee.collect(
+        &ctx.accounts.user,
+        &ctx.accounts.system_program,
+        CredentialType::Project,
+        payment_method,
+    )?;
+    let profile = &mut ctx.accounts.profile;
+    profile.init_if_new(*ctx.accounts.user.key, ctx.bumps.profile);
+    let now = Clock::get()?.unix_timestamp;
     let project = &mut ctx.accounts.project;
     project.user_address = *ctx.accounts.user.key;
-    project.project_name = project_name.clone();
-    project.project_description = project_description;
-    project.collaborators = collaborators;
-    project.start_date = start_date;
-    project.end_date = end_date;
-    project.currently_working = currently_working;
-    project.project_link = project_link;
-    project.timestamp = Clock::get()?.unix_timestamp as i32; 
+    project.set_details(details);
+    project.validate(now)?;
+    project.timestamp = now as i32;
     project.status = VerificationStatus::Pending;
+    project.index = profile.project_count;
+    project.bump = ctx.bumps.project;
+    profile.project_count += 1;
+    profile.last_submission_at = i64::from(project.timestamp);
     project.verifiers = Vec::new();
 
     emit!(ProjectSubmitted {
         user: *ctx.accounts.user.key,
-        project_name,
+        project_name: project.project_name.clone(),
         timestamp: project.timestamp, 
     });
 
@@ -96,25 +107,32 @@ pub fn submit_project(
         skill_name: String,
         proficiency_level: ProficiencyLevel,
         proof_link: String,
+        expires_at: Option<i64>,
+        payment_method: PaymentMethod,
     ) -> Result<()> {
+        ctx.accounts.fee.collect(
+            &ctx.accounts.user,
+            &ctx.accounts.system_program,
+            CredentialType::Skill,
+            payment_method,
+        )?;
+        let profile = &mut ctx.accounts.profile;
+        profile.init_if_new(*ctx.accounts.user.key, ctx.bumps.profile);
 
-                let cpi_context = CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.user.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
-            },
-        );
-        
-        system_program::transfer(cpi_context, UPLOAD_FEE)?;
-
+        let now = Clock::get()?.unix_timestamp;
         let skill = &mut ctx.accounts.skill;
         skill.user_address = *ctx.accounts.user.key;
         skill.skill_name = skill_name.clone();
         skill.proficiency_level = proficiency_level;
         skill.proof_link = proof_link;
-        skill.timestamp = Clock::get()?.unix_timestamp;
+        skill.expires_at = expires_at;
+        skill.validate(now)?;
+        skill.timestamp = now;
         skill.status = VerificationStatus::Pending;
+        skill.index = profile.skill_count;
+        skill.bump = ctx.bumps.skill;
+        profile.skill_count += 1;
+        profile.last_submission_at = skill.timestamp;
         skill.verifiers = Vec::new();
 
         emit!(SkillSubmitted {
@@ -128,37 +146,34 @@ pub fn submit_project(
 
     pub fn submit_employment(
         ctx: Context<EmploymentSubmitCredential>,
-        company_name: String,
-        job_title: String,
-        start_date: i64,
-        end_date: Option<i64>,
-        currently_working: Option<bool>,
+        details: EmploymentDetails,
+        payment_method: PaymentMethod,
     ) -> Result<()> {
+        ctx.accounts.fee.collect(
+            &ctx.accounts.user,
+            &ctx.accounts.system_program,
+            CredentialType::Employment,
+            payment_method,
+        )?;
+        let profile = &mut ctx.accounts.profile;
+        profile.init_if_new(*ctx.accounts.user.key, ctx.bumps.profile);
 
-        let cpi_context = CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.user.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
-            },
-        );
-        
-        system_program::transfer(cpi_context, UPLOAD_FEE)?;
-
+        let now = Clock::get()?.unix_timestamp;
         let employment = &mut ctx.accounts.employment;
         employment.user_address = *ctx.accounts.user.key;
-        employment.company_name = company_name.clone();
-        employment.job_title = job_title;
-        employment.start_date = start_date;
-        employment.end_date = end_date;
-        employment.currently_working = currently_working;
-        employment.timestamp = Clock::get()?.unix_timestamp;
+        employment.set_details(details);
+        employment.validate(now)?;
+        employment.timestamp = now;
         employment.status = VerificationStatus::Pending;
+        employment.index = profile.employment_count;
+        employment.bump = ctx.bumps.employment;
+        profile.employment_count += 1;
+        profile.last_submission_at = employment.timestamp;
         employment.verifiers = Vec::new();
 
         emit!(EmploymentSubmitted {
             user: *ctx.accounts.user.key,
-            company_name,
+            company_name: employment.company_name.clone(),
             timestamp: employment.timestamp,
         });
 
@@ -171,26 +186,33 @@ pub fn submit_project(
         issuer: String,
         date_of_issue: i64,
         proof_link: Option<String>,
+        expires_at: Option<i64>,
+        payment_method: PaymentMethod,
     ) -> Result<()> {
+        ctx.accounts.fee.collect(
+            &ctx.accounts.user,
+            &ctx.accounts.system_program,
+            CredentialType::Certificate,
+            payment_method,
+        )?;
+        let profile = &mut ctx.accounts.profile;
+        profile.init_if_new(*ctx.accounts.user.key, ctx.bumps.profile);
 
-                let cpi_context = CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.user.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
-            },
-        );
-        
-        system_program::transfer(cpi_context, UPLOAD_FEE)?;
-
+        let now = Clock::get()?.unix_timestamp;
         let certificate = &mut ctx.accounts.certificate;
         certificate.user_address = *ctx.accounts.user.key;
         certificate.certification_name = certification_name.clone();
         certificate.issuer = issuer;
         certificate.date_of_issue = date_of_issue;
         certificate.proof_link = proof_link;
-        certificate.timestamp = Clock::get()?.unix_timestamp;
+        certificate.expires_at = expires_at;
+        certificate.validate(now)?;
+        certificate.timestamp = now;
         certificate.status = VerificationStatus::Pending;
+        certificate.index = profile.certificate_count;
+        certificate.bump = ctx.bumps.certificate;
+        profile.certificate_count += 1;
+        profile.last_submission_at = certificate.timestamp;
         certificate.verifiers = Vec::new();
 
         emit!(CertificateSubmitted {
@@ -201,135 +223,1119 @@ pub fn submit_project(
 
         Ok(())
     }
+
+    pub fn update_degree(
+        ctx: Context<DegreeUpdateCredential>,
+        degree_name: String,
+        college_name: String,
+        passout_year: i64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let credential = &mut ctx.accounts.credential;
+        credential.degree_name = degree_name;
+        credential.college_name = college_name;
+        credential.passout_year = passout_year;
+        credential.expires_at = expires_at;
+        credential.validate(now)?;
+        credential.timestamp = now;
+        credential.verifiers = Vec::new();
+        credential.revision += 1;
+
+        emit!(CredentialUpdated {
+            user: *ctx.accounts.user.key,
+            credential: credential.key(),
+            credential_type: CredentialType::Degree,
+            revision: credential.revision,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_project(
+        ctx: Context<ProjectUpdateCredential>,
+        details: ProjectDetails,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let project = &mut ctx.accounts.project;
+        project.set_details(details);
+        project.validate(now)?;
+        project.timestamp = now as i32;
+        project.verifiers = Vec::new();
+        project.revision += 1;
+
+        emit!(CredentialUpdated {
+            user: *ctx.accounts.user.key,
+            credential: project.key(),
+            credential_type: CredentialType::Project,
+            revision: project.revision,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_skill(
+        ctx: Context<SkillUpdateCredential>,
+        skill_name: String,
+        proficiency_level: ProficiencyLevel,
+        proof_link: String,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let skill = &mut ctx.accounts.skill;
+        skill.skill_name = skill_name;
+        skill.proficiency_level = proficiency_level;
+        skill.proof_link = proof_link;
+        skill.expires_at = expires_at;
+        skill.validate(now)?;
+        skill.timestamp = now;
+        skill.verifiers = Vec::new();
+        skill.revision += 1;
+
+        emit!(CredentialUpdated {
+            user: *ctx.accounts.user.key,
+            credential: skill.key(),
+            credential_type: CredentialType::Skill,
+            revision: skill.revision,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_employment(
+        ctx: Context<EmploymentUpdateCredential>,
+        details: EmploymentDetails,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let employment = &mut ctx.accounts.employment;
+        employment.set_details(details);
+        employment.validate(now)?;
+        employment.timestamp = now;
+        employment.verifiers = Vec::new();
+        employment.revision += 1;
+
+        emit!(CredentialUpdated {
+            user: *ctx.accounts.user.key,
+            credential: employment.key(),
+            credential_type: CredentialType::Employment,
+            revision: employment.revision,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_certificate(
+        ctx: Context<CertificateUpdateCredential>,
+        certification_name: String,
+        issuer: String,
+        date_of_issue: i64,
+        proof_link: Option<String>,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let certificate = &mut ctx.accounts.certificate;
+        certificate.certification_name = certification_name;
+        certificate.issuer = issuer;
+        certificate.date_of_issue = date_of_issue;
+        certificate.proof_link = proof_link;
+        certificate.expires_at = expires_at;
+        certificate.validate(now)?;
+        certificate.timestamp = now;
+        certificate.verifiers = Vec::new();
+        certificate.revision += 1;
+
+        emit!(CredentialUpdated {
+            user: *ctx.accounts.user.key,
+            credential: certificate.key(),
+            credential_type: CredentialType::Certificate,
+            revision: certificate.revision,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    pub fn close_degree(ctx: Context<DegreeCloseCredential>) -> Result<()> {
+        let credential = &ctx.accounts.credential;
+        ctx.accounts
+            .profile
+            .record_withdrawal(CredentialType::Degree, &credential.status);
+
+        emit!(CredentialWithdrawn {
+            user: *ctx.accounts.user.key,
+            credential: credential.key(),
+            credential_type: CredentialType::Degree,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn close_project(ctx: Context<ProjectCloseCredential>) -> Result<()> {
+        let project = &ctx.accounts.project;
+        ctx.accounts
+            .profile
+            .record_withdrawal(CredentialType::Project, &project.status);
+
+        emit!(CredentialWithdrawn {
+            user: *ctx.accounts.user.key,
+            credential: project.key(),
+            credential_type: CredentialType::Project,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn close_skill(ctx: Context<SkillCloseCredential>) -> Result<()> {
+        let skill = &ctx.accounts.skill;
+        ctx.accounts
+            .profile
+            .record_withdrawal(CredentialType::Skill, &skill.status);
+
+        emit!(CredentialWithdrawn {
+            user: *ctx.accounts.user.key,
+            credential: skill.key(),
+            credential_type: CredentialType::Skill,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn close_employment(ctx: Context<EmploymentCloseCredential>) -> Result<()> {
+        let employment = &ctx.accounts.employment;
+        ctx.accounts
+            .profile
+            .record_withdrawal(CredentialType::Employment, &employment.status);
+
+        emit!(CredentialWithdrawn {
+            user: *ctx.accounts.user.key,
+            credential: employment.key(),
+            credential_type: CredentialType::Employment,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn close_certificate(ctx: Context<CertificateCloseCredential>) -> Result<()> {
+        let certificate = &ctx.accounts.certificate;
+        ctx.accounts
+            .profile
+            .record_withdrawal(CredentialType::Certificate, &certificate.status);
+
+        emit!(CredentialWithdrawn {
+            user: *ctx.accounts.user.key,
+            credential: certificate.key(),
+            credential_type: CredentialType::Certificate,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+pub fn initialize_config(
+    ctx: Context<InitializeConfig>,
+    verifier_authority: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = *ctx.accounts.admin.key;
+    config.verifier_authority = verifier_authority;
+    config.verifiers = Vec::new();
+    config.bump = ctx.bumps.config;
+
+    Ok(())
+}
+
+pub fn set_verifier_authority(
+    ctx: Context<UpdateConfig>,
+    verifier_authority: Pubkey,
+) -> Result<()> {
+    ctx.accounts.config.verifier_authority = verifier_authority;
+
+    Ok(())
+}
+
+pub fn add_verifier(ctx: Context<UpdateConfig>, verifier: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(
+        !config.verifiers.contains(&verifier),
+        TabluError::VerifierAlreadyAuthorized
+    );
+    require!(
+        config.verifiers.len() < MAX_AUTHORIZED_VERIFIERS,
+        TabluError::TooManyVerifiers
+    );
+    config.verifiers.push(verifier);
+
+    Ok(())
+}
+
+pub fn remove_verifier(ctx: Context<UpdateConfig>, verifier: Pubkey) -> Result<()> {
+    ctx.accounts.config.remove_verifier(&verifier)
+}
+
+pub fn initialize_fee_config(
+    ctx: Context<InitializeFeeConfig>,
+    treasury: Pubkey,
+    fees: FeeSchedule,
+) -> Result<()> {
+    let fee_config = &mut ctx.accounts.fee_config;
+    fee_config.admin = *ctx.accounts.admin.key;
+    fee_config.treasury = treasury;
+    fee_config.fees = fees;
+    fee_config.token_mint = Pubkey::default();
+    fee_config.token_fees = FeeSchedule::default();
+    fee_config.bump = ctx.bumps.fee_config;
+
+    Ok(())
+}
+
+pub fn update_fee_config(
+    ctx: Context<UpdateFeeConfig>,
+    treasury: Pubkey,
+    fees: FeeSchedule,
+) -> Result<()> {
+    let fee_config = &mut ctx.accounts.fee_config;
+    fee_config.treasury = treasury;
+    fee_config.fees = fees;
+
+    emit!(FeeConfigUpdated {
+        treasury,
+        fees: fee_config.fees.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn update_token_fee_config(
+    ctx: Context<UpdateFeeConfig>,
+    token_mint: Pubkey,
+    token_fees: FeeSchedule,
+) -> Result<()> {
+    let fee_config = &mut ctx.accounts.fee_config;
+    fee_config.token_mint = token_mint;
+    fee_config.token_fees = token_fees;
+
+    emit!(TokenFeeConfigUpdated {
+        token_mint,
+        token_fees: fee_config.token_fees.clone(),
+    });
+
+    Ok(())
+}
+
 pub fn update_degree_verification_status(
     ctx: Context<UpdateDegreeVerification>,
     new_status: VerificationStatus,
+    verifiers: Vec<Pubkey>,
 ) -> Result<()> {
-    let credential = &mut ctx.accounts.credential;
-    credential.status = new_status.clone();
-
-    Ok(())
+    let credential: &mut UserDegreeCredential = &mut ctx.accounts.credential;
+    record_verification(
+        &mut ctx.accounts.profile,
+        CredentialType::Degree,
+        &mut credential.status,
+        &mut credential.verifiers,
+        new_status,
+        verifiers,
+    )
 }
 
 pub fn update_project_verification_status(
     ctx: Context<UpdateProjectVerification>,
     new_status: VerificationStatus,
+    verifiers: Vec<Pubkey>,
 ) -> Result<()> {
-    let credential = &mut ctx.accounts.credential;
-    credential.status = new_status.clone();
-    Ok(())
+    let credential: &mut ProjectCredential = &mut ctx.accounts.credential;
+    record_verification(
+        &mut ctx.accounts.profile,
+        CredentialType::Project,
+        &mut credential.status,
+        &mut credential.verifiers,
+        new_status,
+        verifiers,
+    )
 }
 
 pub fn update_skill_verification_status(
     ctx: Context<UpdateSkillVerification>,
     new_status: VerificationStatus,
+    verifiers: Vec<Pubkey>,
 ) -> Result<()> {
-    let credential = &mut ctx.accounts.credential;
-    credential.status = new_status.clone();
-
-    Ok(())
+    let credential: &mut SkillCredential = &mut ctx.accounts.credential;
+    record_verification(
+        &mut ctx.accounts.profile,
+        CredentialType::Skill,
+        &mut credential.status,
+        &mut credential.verifiers,
+        new_status,
+        verifiers,
+    )
 }
 
 pub fn update_employment_verification_status(
     ctx: Context<UpdateEmploymentVerification>,
     new_status: VerificationStatus,
+    verifiers: Vec<Pubkey>,
+) -> Result<()> {
+    let credential: &mut EmploymentCredential = &mut ctx.accounts.credential;
+    record_verification(
+        &mut ctx.accounts.profile,
+        CredentialType::Employment,
+        &mut credential.status,
+        &mut credential.verifiers,
+        new_status,
+        verifiers,
+    )
+}
+
+pub fn update_certificate_verification_status(
+    ctx: Context<UpdateCertificateVerification>,
+    new_status: VerificationStatus,
+    verifiers: Vec<Pubkey>,
+) -> Result<()> {
+    let credential: &mut CertificateCredential = &mut ctx.accounts.credential;
+    record_verification(
+        &mut ctx.accounts.profile,
+        CredentialType::Certificate,
+        &mut credential.status,
+        &mut credential.verifiers,
+        new_status,
+        verifiers,
+    )
+}
+
+pub fn revoke_credential(
+    ctx: Context<RevokeCredential>,
+    credential_type: CredentialType,
+    reason: RevocationReason,
 ) -> Result<()> {
-    let credential = &mut ctx.accounts.credential;
-    credential.status = new_status.clone();
+    let info = ctx.accounts.credential.to_account_info();
+    let mut credential = load_credential(&info, credential_type.clone())?;
+    require!(
+        ctx.accounts.profile.user == credential.user_address(),
+        TabluError::ProfileMismatch
+    );
+    require!(
+        matches!(
+            credential.status(),
+            VerificationStatus::Pending | VerificationStatus::Verified
+        ),
+        TabluError::NotRevocable
+    );
+
+    ctx.accounts.profile.record_status_change(
+        credential_type.clone(),
+        credential.status(),
+        &VerificationStatus::Revoked,
+    );
+    credential.set_status(VerificationStatus::Revoked);
+    credential.store(&info)?;
 
+    emit!(CredentialRevoked {
+        credential: info.key(),
+        credential_type,
+        authority: *ctx.accounts.authority.key,
+        reason,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
 
-pub fn update_certificate_verification_status(
-    ctx: Context<UpdateCertificateVerification>,
+/// Permissionlessly moves a verified credential past its `expires_at` to `Expired`.
+pub fn expire_credential(
+    ctx: Context<ExpireCredential>,
+    credential_type: CredentialType,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = ctx.accounts.credential.to_account_info();
+    let mut credential = load_credential(&info, credential_type.clone())?;
+    require!(
+        ctx.accounts.profile.user == credential.user_address(),
+        TabluError::ProfileMismatch
+    );
+    require!(
+        *credential.status() == VerificationStatus::Verified,
+        TabluError::NotVerified
+    );
+    require!(
+        credential.expires_at().is_some_and(|expires_at| expires_at <= now),
+        TabluError::NotExpired
+    );
+
+    ctx.accounts.profile.record_status_change(
+        credential_type.clone(),
+        credential.status(),
+        &VerificationStatus::Expired,
+    );
+    credential.set_status(VerificationStatus::Expired);
+    credential.store(&info)?;
+
+    emit!(CredentialExpired {
+        credential: info.key(),
+        credential_type,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Returns whether the credential is verified and unexpired. Intended for CPI
+/// callers, which read the result from the instruction's return data.
+pub fn is_currently_valid(
+    ctx: Context<CheckCredential>,
+    credential_type: CredentialType,
+) -> Result<bool> {
+    let info = ctx.accounts.credential.to_account_info();
+    let credential = load_credential(&info, credential_type)?;
+
+    Ok(credential.is_currently_valid(Clock::get()?.unix_timestamp))
+}
+}
+
+
+fn year_of(timestamp: i64) -> i64 {
+    1970 + timestamp.div_euclid(SECONDS_PER_YEAR)
+}
+
+fn current_year(now: i64) -> i64 {
+    year_of(now)
+}
+
+/// Whether `year` lies between `MIN_PASSOUT_YEAR` and
+/// `MAX_FUTURE_PASSOUT_YEARS` past the current year.
+fn is_plausible_year(year: i64, now: i64) -> bool {
+    (MIN_PASSOUT_YEAR..=current_year(now) + MAX_FUTURE_PASSOUT_YEARS).contains(&year)
+}
+
+/// Checks a start/end period: both dates must fall in plausible years, it
+/// must have started, must not end before it started, and cannot have an end
+/// date while still ongoing.
+fn validate_period(
+    start_date: i64,
+    end_date: Option<i64>,
+    currently_working: Option<bool>,
+    now: i64,
+) -> Result<()> {
+    require!(start_date <= now, TabluError::DateInFuture);
+    require!(
+        is_plausible_year(year_of(start_date), now),
+        TabluError::InvalidPeriodYear
+    );
+    if let Some(end_date) = end_date {
+        require!(
+            currently_working != Some(true),
+            TabluError::EndDateWhileCurrent
+        );
+        require!(end_date >= start_date, TabluError::EndBeforeStart);
+        require!(
+            is_plausible_year(year_of(end_date), now),
+            TabluError::InvalidPeriodYear
+        );
+    }
+
+    Ok(())
+}
+
+/// An expiry, when set, must come after the moment the credential took effect:
+/// its issue or start date, or its submission for credentials with neither.
+fn validate_expiry(expires_at: Option<i64>, effective_from: i64) -> Result<()> {
+    require!(
+        expires_at.iter().all(|expires_at| *expires_at > effective_from),
+        TabluError::InvalidExpiry
+    );
+
+    Ok(())
+}
+
+/// Accepts `http(s)://` links with a dotted host and no whitespace.
+fn is_well_formed_url(link: &str) -> bool {
+    let Some(rest) = link
+        .strip_prefix("https://")
+        .or_else(|| link.strip_prefix("http://"))
+    else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+
+    host.contains('.')
+        && !host.starts_with('.')
+        && !host.ends_with('.')
+        && !link.chars().any(char::is_whitespace)
+}
+
+/// Applies a verification outcome, appending any verifiers not already recorded.
+/// Revoked and expired credentials are final and take no further outcomes.
+fn record_verification(
+    profile: &mut UserProfile,
+    credential_type: CredentialType,
+    status: &mut VerificationStatus,
+    verifiers: &mut Vec<Pubkey>,
     new_status: VerificationStatus,
+    new_verifiers: Vec<Pubkey>,
 ) -> Result<()> {
-    let credential = &mut ctx.accounts.credential;
-    credential.status = new_status.clone();
+    require!(
+        !matches!(
+            status,
+            VerificationStatus::Revoked | VerificationStatus::Expired
+        ),
+        TabluError::CredentialRetired
+    );
+    profile.record_status_change(credential_type, status, &new_status);
+    *status = new_status;
+    for verifier in new_verifiers {
+        if !verifiers.contains(&verifier) {
+            require!(
+                verifiers.len() < MAX_CREDENTIAL_VERIFIERS,
+                TabluError::TooManyCredentialVerifiers
+            );
+            verifiers.push(verifier);
+        }
+    }
 
     Ok(())
 }
+
+/// Common view over the five credential account types, used by instructions
+/// that take a `CredentialType` instead of a concrete account.
+pub trait CredentialAccount {
+    fn user_address(&self) -> Pubkey;
+    fn status(&self) -> &VerificationStatus;
+    fn revision(&self) -> u32;
+    fn set_status(&mut self, status: VerificationStatus);
+    fn store(&self, info: &AccountInfo) -> Result<()>;
+    fn expires_at(&self) -> Option<i64>;
+
+    fn is_currently_valid(&self, now: i64) -> bool {
+        *self.status() == VerificationStatus::Verified
+            && self.expires_at().iter().all(|expires_at| now < *expires_at)
+    }
+}
+
+macro_rules! impl_credential_account {
+    ($account:ty) => {
+        impl CredentialAccount for $account {
+            fn user_address(&self) -> Pubkey {
+                self.user_address
+            }
+
+            fn status(&self) -> &VerificationStatus {
+                &self.status
+            }
+
+            fn revision(&self) -> u32 {
+                self.revision
+            }
+
+            fn set_status(&mut self, status: VerificationStatus) {
+                self.status = status;
+            }
+
+            fn store(&self, info: &AccountInfo) -> Result<()> {
+                let mut data = info.try_borrow_mut_data()?;
+                let mut writer: &mut [u8] = &mut data;
+                self.try_serialize(&mut writer)
+            }
+
+            fn expires_at(&self) -> Option<i64> {
+                self.expires_at
+            }
+        }
+    };
+}
+
+impl_credential_account!(UserDegreeCredential);
+impl_credential_account!(ProjectCredential);
+impl_credential_account!(SkillCredential);
+impl_credential_account!(EmploymentCredential);
+impl_credential_account!(CertificateCredential);
+
+/// Deserializes `info` as the given credential type, checking owner and discriminator.
+pub fn load_credential(
+    info: &AccountInfo,
+    credential_type: CredentialType,
+) -> Result<Box<dyn CredentialAccount>> {
+    Ok(match credential_type {
+        CredentialType::Degree => Box::new(deserialize_credential::<UserDegreeCredential>(info)?),
+        CredentialType::Project => Box::new(deserialize_credential::<ProjectCredential>(info)?),
+        CredentialType::Skill => Box::new(deserialize_credential::<SkillCredential>(info)?),
+        CredentialType::Employment => {
+            Box::new(deserialize_credential::<EmploymentCredential>(info)?)
+        }
+        CredentialType::Certificate => {
+            Box::new(deserialize_credential::<CertificateCredential>(info)?)
+        }
+    })
 }
 
+fn deserialize_credential<T: AccountDeserialize>(info: &AccountInfo) -> Result<T> {
+    require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    let data = info.try_borrow_data()?;
+    T::try_deserialize(&mut &data[..])
+}
 
 #[derive(Accounts)]
 pub struct DegreeSubmitCredential<'info> {
-    #[account(init, payer = user, space = 8 + 32 + 128 + 128 + 8 + 1 + 8)]
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserProfile::INIT_SPACE,
+        seeds = [b"profile", user.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + UserDegreeCredential::INIT_SPACE,
+        seeds = [b"degree", user.key().as_ref(), &profile.degree_count.to_le_bytes()],
+        bump
+    )]
     pub credential: Account<'info, UserDegreeCredential>,
     #[account(mut)]
     pub user: Signer<'info>,
-    /// CHECK: Treasury wallet that receives fees
-    #[account(
-        mut,
-        constraint = treasury.key() == Pubkey::try_from(TREASURY_WALLET).unwrap()
-    )]
-    pub treasury: AccountInfo<'info>,
+    pub fee: SubmissionFee<'info>,
     pub system_program: Program<'info, System>,
 }
 #[derive(Accounts)]
 pub struct ProjectSubmitCredential<'info> {
-    #[account(init, payer = user, space = 8 + 32 + 256 + 512 + 256 + 8 + 8 + 1 + 256 + 8 + 1 + 256)]
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserProfile::INIT_SPACE,
+        seeds = [b"profile", user.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + ProjectCredential::INIT_SPACE,
+        seeds = [b"project", user.key().as_ref(), &profile.project_count.to_le_bytes()],
+        bump
+    )]
     pub project: Account<'info, ProjectCredential>,
     #[account(mut)]
     pub user: Signer<'info>,
-    /// CHECK: Treasury wallet that receives fees
-    #[account(
-        mut,
-        constraint = treasury.key() == Pubkey::try_from(TREASURY_WALLET).unwrap()
-    )]
-    pub treasury: AccountInfo<'info>,
+    pub fee: SubmissionFee<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 pub struct SkillSubmitCredential<'info> {
-    #[account(init, payer = user, space = 8 + 32 + 128 + 1 + 256  + 8 + 1 + 256)]
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserProfile::INIT_SPACE,
+        seeds = [b"profile", user.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + SkillCredential::INIT_SPACE,
+        seeds = [b"skill", user.key().as_ref(), &profile.skill_count.to_le_bytes()],
+        bump
+    )]
     pub skill: Account<'info, SkillCredential>,
     #[account(mut)]
     pub user: Signer<'info>,
-    /// CHECK: Treasury wallet that receives fees
-    #[account(
-        mut,
-        constraint = treasury.key() == Pubkey::try_from(TREASURY_WALLET).unwrap()
-    )]
-    pub treasury: AccountInfo<'info>,
+    pub fee: SubmissionFee<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 pub struct EmploymentSubmitCredential<'info> {
-    #[account(init, payer = user, space = 8 + 32 + 128 + 128 + 8 + 8 + 1 + 8 + 1 + 256)]
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserProfile::INIT_SPACE,
+        seeds = [b"profile", user.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + EmploymentCredential::INIT_SPACE,
+        seeds = [b"employment", user.key().as_ref(), &profile.employment_count.to_le_bytes()],
+        bump
+    )]
     pub employment: Account<'info, EmploymentCredential>,
     #[account(mut)]
     pub user: Signer<'info>,
-    /// CHECK: Treasury wallet that receives fees
-    #[account(
-        mut,
-        constraint = treasury.key() == Pubkey::try_from(TREASURY_WALLET).unwrap()
-    )]
-    pub treasury: AccountInfo<'info>,
+    pub fee: SubmissionFee<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 pub struct CertificateSubmitCredential<'info> {
-    #[account(init, payer = user, space = 8 + 32 + 128 + 128 + 8 + 256 + 8 + 1 + 256)]
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserProfile::INIT_SPACE,
+        seeds = [b"profile", user.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + CertificateCredential::INIT_SPACE,
+        seeds = [b"certificate", user.key().as_ref(), &profile.certificate_count.to_le_bytes()],
+        bump
+    )]
     pub certificate: Account<'info, CertificateCredential>,
     #[account(mut)]
     pub user: Signer<'info>,
-    /// CHECK: Treasury wallet that receives fees
+    pub fee: SubmissionFee<'info>,
+    pub system_program: Program<'info, System>,
+}
+#[derive(Accounts)]
+pub struct DegreeUpdateCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"degree", user.key().as_ref(), &credential.index.to_le_bytes()],
+        bump = credential.bump,
+        constraint = credential.user_address == user.key() @ TabluError::NotCredentialOwner,
+        constraint = credential.status == VerificationStatus::Pending @ TabluError::NotPending
+    )]
+    pub credential: Account<'info, UserDegreeCredential>,
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DegreeCloseCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"degree", user.key().as_ref(), &credential.index.to_le_bytes()],
+        bump = credential.bump,
+        constraint = credential.user_address == user.key() @ TabluError::NotCredentialOwner,
+        close = user
+    )]
+    pub credential: Account<'info, UserDegreeCredential>,
+    #[account(mut, seeds = [b"profile", user.key().as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, UserProfile>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProjectUpdateCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", user.key().as_ref(), &project.index.to_le_bytes()],
+        bump = project.bump,
+        constraint = project.user_address == user.key() @ TabluError::NotCredentialOwner,
+        constraint = project.status == VerificationStatus::Pending @ TabluError::NotPending
+    )]
+    pub project: Account<'info, ProjectCredential>,
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProjectCloseCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", user.key().as_ref(), &project.index.to_le_bytes()],
+        bump = project.bump,
+        constraint = project.user_address == user.key() @ TabluError::NotCredentialOwner,
+        close = user
+    )]
+    pub project: Account<'info, ProjectCredential>,
+    #[account(mut, seeds = [b"profile", user.key().as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, UserProfile>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SkillUpdateCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"skill", user.key().as_ref(), &skill.index.to_le_bytes()],
+        bump = skill.bump,
+        constraint = skill.user_address == user.key() @ TabluError::NotCredentialOwner,
+        constraint = skill.status == VerificationStatus::Pending @ TabluError::NotPending
+    )]
+    pub skill: Account<'info, SkillCredential>,
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SkillCloseCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"skill", user.key().as_ref(), &skill.index.to_le_bytes()],
+        bump = skill.bump,
+        constraint = skill.user_address == user.key() @ TabluError::NotCredentialOwner,
+        close = user
+    )]
+    pub skill: Account<'info, SkillCredential>,
+    #[account(mut, seeds = [b"profile", user.key().as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, UserProfile>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct EmploymentUpdateCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"employment", user.key().as_ref(), &employment.index.to_le_bytes()],
+        bump = employment.bump,
+        constraint = employment.user_address == user.key() @ TabluError::NotCredentialOwner,
+        constraint = employment.status == VerificationStatus::Pending @ TabluError::NotPending
+    )]
+    pub employment: Account<'info, EmploymentCredential>,
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct EmploymentCloseCredential<'info> {
     #[account(
         mut,
-        constraint = treasury.key() == Pubkey::try_from(TREASURY_WALLET).unwrap()
+        seeds = [b"employment", user.key().as_ref(), &employment.index.to_le_bytes()],
+        bump = employment.bump,
+        constraint = employment.user_address == user.key() @ TabluError::NotCredentialOwner,
+        close = user
+    )]
+    pub employment: Account<'info, EmploymentCredential>,
+    #[account(mut, seeds = [b"profile", user.key().as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, UserProfile>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CertificateUpdateCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"certificate", user.key().as_ref(), &certificate.index.to_le_bytes()],
+        bump = certificate.bump,
+        constraint = certificate.user_address == user.key() @ TabluError::NotCredentialOwner,
+        constraint = certificate.status == VerificationStatus::Pending @ TabluError::NotPending
+    )]
+    pub certificate: Account<'info, CertificateCredential>,
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CertificateCloseCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"certificate", user.key().as_ref(), &certificate.index.to_le_bytes()],
+        bump = certificate.bump,
+        constraint = certificate.user_address == user.key() @ TabluError::NotCredentialOwner,
+        close = user
+    )]
+    pub certificate: Account<'info, CertificateCredential>,
+    #[account(mut, seeds = [b"profile", user.key().as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, UserProfile>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeCredential<'info> {
+    /// CHECK: Deserialized as the given `CredentialType` by the handler
+    #[account(mut)]
+    pub credential: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"profile", profile.user.as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, UserProfile>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_authorized(authority.key) @ TabluError::UnauthorizedVerifier
     )]
+    pub config: Account<'info, ProgramConfig>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireCredential<'info> {
+    /// CHECK: Deserialized as the given `CredentialType` by the handler
+    #[account(mut)]
+    pub credential: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"profile", profile.user.as_ref()], bump = profile.bump)]
+    pub profile: Account<'info, UserProfile>,
+}
+
+#[derive(Accounts)]
+pub struct CheckCredential<'info> {
+    /// CHECK: Deserialized as the given `CredentialType` by the handler
+    pub credential: UncheckedAccount<'info>,
+}
+
+/// Fee accounts shared by every `submit_*` instruction. The token accounts are
+/// only required when paying with `PaymentMethod::Token`.
+#[derive(Accounts)]
+pub struct SubmissionFee<'info> {
+    #[account(seeds = [b"fee_config"], bump = fee_config.bump)]
+    pub fee_config: Account<'info, FeeConfig>,
+    /// CHECK: Treasury wallet that receives fees
+    #[account(mut, address = fee_config.treasury @ TabluError::InvalidTreasury)]
     pub treasury: AccountInfo<'info>,
+    #[account(address = fee_config.token_mint @ TabluError::InvalidTokenMint)]
+    pub token_mint: Option<Account<'info, Mint>>,
+    #[account(mut, token::mint = fee_config.token_mint)]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = fee_config.token_mint,
+        associated_token::authority = fee_config.treasury
+    )]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+impl<'info> SubmissionFee<'info> {
+    pub fn collect(
+        &self,
+        user: &Signer<'info>,
+        system_program: &Program<'info, System>,
+        credential_type: CredentialType,
+        payment_method: PaymentMethod,
+    ) -> Result<()> {
+        match payment_method {
+            PaymentMethod::Sol => {
+                let cpi_context = CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: user.to_account_info(),
+                        to: self.treasury.to_account_info(),
+                    },
+                );
+
+                system_program::transfer(
+                    cpi_context,
+                    self.fee_config.fees.fee_for(credential_type),
+                )
+            }
+            PaymentMethod::Token => {
+                require!(
+                    self.fee_config.token_mint != Pubkey::default(),
+                    TabluError::TokenPaymentDisabled
+                );
+                let (
+                    Some(token_mint),
+                    Some(user_token_account),
+                    Some(treasury_token_account),
+                    Some(token_program),
+                ) = (
+                    &self.token_mint,
+                    &self.user_token_account,
+                    &self.treasury_token_account,
+                    &self.token_program,
+                )
+                else {
+                    return err!(TabluError::MissingTokenAccounts);
+                };
+
+                let cpi_context = CpiContext::new(
+                    token_program.to_account_info(),
+                    token::TransferChecked {
+                        from: user_token_account.to_account_info(),
+                        mint: token_mint.to_account_info(),
+                        to: treasury_token_account.to_account_info(),
+                        authority: user.to_account_info(),
+                    },
+                );
+
+                token::transfer_checked(
+                    cpi_context,
+                    self.fee_config.token_fees.fee_for(credential_type),
+                    token_mint.decimals,
+                )
+            }
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProgramConfig::INIT_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Tablu>,
+    #[account(constraint = is_upgrade_authority(&program_data, admin.key) @ TabluError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ TabluError::Unauthorized
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeFeeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + FeeConfig::INIT_SPACE,
+        seeds = [b"fee_config"],
+        bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Tablu>,
+    #[account(constraint = is_upgrade_authority(&program_data, admin.key) @ TabluError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
     pub system_program: Program<'info, System>,
 }
+
+#[derive(Accounts)]
+pub struct UpdateFeeConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_config"],
+        bump = fee_config.bump,
+        has_one = admin @ TabluError::Unauthorized
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    pub admin: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct UpdateDegreeVerification<'info> {
     #[account(mut)]
     pub credential: Account<'info, UserDegreeCredential>,
+    #[account(
+        mut,
+        seeds = [b"profile", credential.user_address.as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_authorized(authority.key) @ TabluError::UnauthorizedVerifier
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub authority: Signer<'info>,
 }
 
@@ -337,6 +1343,18 @@ pub struct UpdateDegreeVerification<'info> {
 pub struct UpdateProjectVerification<'info> {
     #[account(mut)]
     pub credential: Account<'info, ProjectCredential>,
+    #[account(
+        mut,
+        seeds = [b"profile", credential.user_address.as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_authorized(authority.key) @ TabluError::UnauthorizedVerifier
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub authority: Signer<'info>,
 }
 
@@ -344,6 +1362,18 @@ pub struct UpdateProjectVerification<'info> {
 pub struct UpdateSkillVerification<'info> {
     #[account(mut)]
     pub credential: Account<'info, SkillCredential>,
+    #[account(
+        mut,
+        seeds = [b"profile", credential.user_address.as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_authorized(authority.key) @ TabluError::UnauthorizedVerifier
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub authority: Signer<'info>,
 }
 
@@ -351,6 +1381,18 @@ pub struct UpdateSkillVerification<'info> {
 pub struct UpdateEmploymentVerification<'info> {
     #[account(mut)]
     pub credential: Account<'info, EmploymentCredential>,
+    #[account(
+        mut,
+        seeds = [b"profile", credential.user_address.as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_authorized(authority.key) @ TabluError::UnauthorizedVerifier
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub authority: Signer<'info>,
 }
 
@@ -358,70 +1400,435 @@ pub struct UpdateEmploymentVerification<'info> {
 pub struct UpdateCertificateVerification<'info> {
     #[account(mut)]
     pub credential: Account<'info, CertificateCredential>,
+    #[account(
+        mut,
+        seeds = [b"profile", credential.user_address.as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_authorized(authority.key) @ TabluError::UnauthorizedVerifier
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub authority: Signer<'info>,
 }
 
 #[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+    pub admin: Pubkey,
+    /// PDA of the `credential_verifier` program allowed to settle verification outcomes.
+    pub verifier_authority: Pubkey,
+    #[max_len(MAX_AUTHORIZED_VERIFIERS)]
+    pub verifiers: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+/// Only the program's upgrade authority may create the admin configs, so they
+/// cannot be claimed by whoever calls first after deployment.
+pub fn is_upgrade_authority(program_data: &ProgramData, signer: &Pubkey) -> bool {
+    program_data.upgrade_authority_address == Some(*signer)
+}
+
+impl ProgramConfig {
+    pub fn is_authorized(&self, authority: &Pubkey) -> bool {
+        *authority == self.verifier_authority || self.verifiers.contains(authority)
+    }
+
+    pub fn remove_verifier(&mut self, verifier: &Pubkey) -> Result<()> {
+        let position = self
+            .verifiers
+            .iter()
+            .position(|key| key == verifier)
+            .ok_or(TabluError::VerifierNotFound)?;
+        self.verifiers.remove(position);
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FeeConfig {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub fees: FeeSchedule,
+    /// SPL mint accepted as an alternative to SOL; the default key disables it.
+    pub token_mint: Pubkey,
+    pub token_fees: FeeSchedule,
+    pub bump: u8,
+}
+
+/// Submission fee for each credential type, in lamports or token base units.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub struct FeeSchedule {
+    pub degree: u64,
+    pub project: u64,
+    pub skill: u64,
+    pub employment: u64,
+    pub certificate: u64,
+}
+
+impl FeeSchedule {
+    pub fn fee_for(&self, credential_type: CredentialType) -> u64 {
+        match credential_type {
+            CredentialType::Degree => self.degree,
+            CredentialType::Project => self.project,
+            CredentialType::Skill => self.skill,
+            CredentialType::Employment => self.employment,
+            CredentialType::Certificate => self.certificate,
+        }
+    }
+}
+
+/// Aggregate of a wallet's credentials. Each submitted count is also the index
+/// of that wallet's next credential PDA of the same type.
+#[account]
+#[derive(InitSpace)]
+pub struct UserProfile {
+    pub user: Pubkey,
+    pub degree_count: u32,
+    pub project_count: u32,
+    pub skill_count: u32,
+    pub employment_count: u32,
+    pub certificate_count: u32,
+    pub verified_degree_count: u32,
+    pub verified_project_count: u32,
+    pub verified_skill_count: u32,
+    pub verified_employment_count: u32,
+    pub verified_certificate_count: u32,
+    pub last_submission_at: i64,
+    pub bump: u8,
+}
+
+impl UserProfile {
+    pub fn init_if_new(&mut self, user: Pubkey, bump: u8) {
+        if self.user == Pubkey::default() {
+            self.user = user;
+            self.bump = bump;
+        }
+    }
+
+    fn verified_count_mut(&mut self, credential_type: CredentialType) -> &mut u32 {
+        match credential_type {
+            CredentialType::Degree => &mut self.verified_degree_count,
+            CredentialType::Project => &mut self.verified_project_count,
+            CredentialType::Skill => &mut self.verified_skill_count,
+            CredentialType::Employment => &mut self.verified_employment_count,
+            CredentialType::Certificate => &mut self.verified_certificate_count,
+        }
+    }
+
+    pub fn record_withdrawal(
+        &mut self,
+        credential_type: CredentialType,
+        status: &VerificationStatus,
+    ) {
+        if *status == VerificationStatus::Verified {
+            let verified_count = self.verified_count_mut(credential_type);
+            *verified_count = verified_count.saturating_sub(1);
+        }
+    }
+
+    pub fn record_status_change(
+        &mut self,
+        credential_type: CredentialType,
+        old_status: &VerificationStatus,
+        new_status: &VerificationStatus,
+    ) {
+        let was_verified = *old_status == VerificationStatus::Verified;
+        let is_verified = *new_status == VerificationStatus::Verified;
+        let verified_count = self.verified_count_mut(credential_type);
+        if is_verified && !was_verified {
+            *verified_count += 1;
+        } else if was_verified && !is_verified {
+            *verified_count = verified_count.saturating_sub(1);
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
 pub struct UserDegreeCredential {
     pub user_address: Pubkey,
+    #[max_len(MAX_NAME_LEN)]
     pub degree_name: String,
+    #[max_len(MAX_NAME_LEN)]
     pub college_name: String,
     pub passout_year: i64,
+    /// Verified status lapses once this timestamp passes.
+    pub expires_at: Option<i64>,
     pub status: VerificationStatus,
     pub timestamp: i64,
+    #[max_len(MAX_CREDENTIAL_VERIFIERS)]
     pub verifiers: Vec<Pubkey>,
+    pub index: u32,
+    pub bump: u8,
+    /// Bumped on every owner edit.
+    pub revision: u32,
+}
+
+impl UserDegreeCredential {
+    pub fn validate(&self, now: i64) -> Result<()> {
+        require!(
+            self.degree_name.len() <= MAX_NAME_LEN,
+            TabluError::DegreeNameTooLong
+        );
+        require!(
+            self.college_name.len() <= MAX_NAME_LEN,
+            TabluError::CollegeNameTooLong
+        );
+        require!(!self.degree_name.trim().is_empty(), TabluError::EmptyName);
+        require!(!self.college_name.trim().is_empty(), TabluError::EmptyName);
+        require!(
+            is_plausible_year(self.passout_year, now),
+            TabluError::InvalidPassoutYear
+        );
+        validate_expiry(self.expires_at, now)
+    }
 }
 #[account]
+#[derive(InitSpace)]
 pub struct ProjectCredential {
     pub user_address: Pubkey,
+    #[max_len(MAX_NAME_LEN)]
     pub project_name: String,
+    #[max_len(MAX_DESCRIPTION_LEN)]
     pub project_description: String,
+    #[max_len(MAX_COLLABORATORS, MAX_COLLABORATOR_LEN)]
     pub collaborators: Option<Vec<String>>,
     pub start_date: i32,
     pub end_date: Option<i32>,
     pub currently_working: Option<bool>,
+    #[max_len(MAX_LINK_LEN)]
     pub project_link: String,
+    /// Verified status lapses once this timestamp passes.
+    pub expires_at: Option<i64>,
     pub timestamp: i32,
     pub status: VerificationStatus,
+    #[max_len(MAX_CREDENTIAL_VERIFIERS)]
     pub verifiers: Vec<Pubkey>,
+    pub index: u32,
+    pub bump: u8,
+    /// Bumped on every owner edit.
+    pub revision: u32,
+}
+
+/// The owner-supplied fields of a project, as submitted or edited.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ProjectDetails {
+    pub project_name: String,
+    pub project_description: String,
+    pub collaborators: Option<Vec<String>>,
+    pub start_date: i32,
+    pub end_date: Option<i32>,
+    pub currently_working: Option<bool>,
+    pub project_link: String,
+    pub expires_at: Option<i64>,
+}
+
+impl ProjectCredential {
+    pub fn set_details(&mut self, details: ProjectDetails) {
+        self.project_name = details.project_name;
+        self.project_description = details.project_description;
+        self.collaborators = details.collaborators;
+        self.start_date = details.start_date;
+        self.end_date = details.end_date;
+        self.currently_working = details.currently_working;
+        self.project_link = details.project_link;
+        self.expires_at = details.expires_at;
+    }
+
+    pub fn validate(&self, now: i64) -> Result<()> {
+        require!(
+            self.project_name.len() <= MAX_NAME_LEN,
+            TabluError::ProjectNameTooLong
+        );
+        require!(
+            self.project_description.len() <= MAX_DESCRIPTION_LEN,
+            TabluError::ProjectDescriptionTooLong
+        );
+        require!(
+            self.collaborators.as_ref().map_or(0, Vec::len) <= MAX_COLLABORATORS,
+            TabluError::TooManyCollaborators
+        );
+        require!(
+            self.collaborators
+                .iter()
+                .flatten()
+                .all(|name| name.len() <= MAX_COLLABORATOR_LEN),
+            TabluError::CollaboratorNameTooLong
+        );
+        require!(
+            self.project_link.len() <= MAX_LINK_LEN,
+            TabluError::ProjectLinkTooLong
+        );
+        require!(!self.project_name.trim().is_empty(), TabluError::EmptyName);
+        require!(
+            is_well_formed_url(&self.project_link),
+            TabluError::InvalidUrl
+        );
+        validate_period(
+            i64::from(self.start_date),
+            self.end_date.map(i64::from),
+            self.currently_working,
+            now,
+        )?;
+        validate_expiry(self.expires_at, i64::from(self.start_date))
+    }
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct SkillCredential {
     pub user_address: Pubkey,
+    #[max_len(MAX_NAME_LEN)]
     pub skill_name: String,
     pub proficiency_level: ProficiencyLevel,
+    #[max_len(MAX_LINK_LEN)]
     pub proof_link: String,
+    /// Verified status lapses once this timestamp passes.
+    pub expires_at: Option<i64>,
     pub timestamp: i64,
     pub status: VerificationStatus,
+    #[max_len(MAX_CREDENTIAL_VERIFIERS)]
     pub verifiers: Vec<Pubkey>,
+    pub index: u32,
+    pub bump: u8,
+    /// Bumped on every owner edit.
+    pub revision: u32,
+}
+
+impl SkillCredential {
+    pub fn validate(&self, now: i64) -> Result<()> {
+        require!(
+            self.skill_name.len() <= MAX_NAME_LEN,
+            TabluError::SkillNameTooLong
+        );
+        require!(
+            self.proof_link.len() <= MAX_LINK_LEN,
+            TabluError::ProofLinkTooLong
+        );
+        require!(!self.skill_name.trim().is_empty(), TabluError::EmptyName);
+        require!(is_well_formed_url(&self.proof_link), TabluError::InvalidUrl);
+        validate_expiry(self.expires_at, now)
+    }
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct EmploymentCredential {
     pub user_address: Pubkey,
+    #[max_len(MAX_NAME_LEN)]
     pub company_name: String,
+    #[max_len(MAX_NAME_LEN)]
     pub job_title: String,
     pub start_date: i64,
     pub end_date: Option<i64>,
     pub currently_working: Option<bool>,
+    /// Verified status lapses once this timestamp passes.
+    pub expires_at: Option<i64>,
     pub timestamp: i64,
     pub status: VerificationStatus,
+    #[max_len(MAX_CREDENTIAL_VERIFIERS)]
     pub verifiers: Vec<Pubkey>,
+    pub index: u32,
+    pub bump: u8,
+    /// Bumped on every owner edit.
+    pub revision: u32,
+}
+
+/// The owner-supplied fields of an employment record, as submitted or edited.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct EmploymentDetails {
+    pub company_name: String,
+    pub job_title: String,
+    pub start_date: i64,
+    pub end_date: Option<i64>,
+    pub currently_working: Option<bool>,
+    pub expires_at: Option<i64>,
+}
+
+impl EmploymentCredential {
+    pub fn set_details(&mut self, details: EmploymentDetails) {
+        self.company_name = details.company_name;
+        self.job_title = details.job_title;
+        self.start_date = details.start_date;
+        self.end_date = details.end_date;
+        self.currently_working = details.currently_working;
+        self.expires_at = details.expires_at;
+    }
+
+    pub fn validate(&self, now: i64) -> Result<()> {
+        require!(
+            self.company_name.len() <= MAX_NAME_LEN,
+            TabluError::CompanyNameTooLong
+        );
+        require!(
+            self.job_title.len() <= MAX_NAME_LEN,
+            TabluError::JobTitleTooLong
+        );
+        require!(!self.company_name.trim().is_empty(), TabluError::EmptyName);
+        require!(!self.job_title.trim().is_empty(), TabluError::EmptyName);
+        validate_period(
+            self.start_date,
+            self.end_date,
+            self.currently_working,
+            now,
+        )?;
+        validate_expiry(self.expires_at, self.start_date)
+    }
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct CertificateCredential {
     pub user_address: Pubkey,
+    #[max_len(MAX_NAME_LEN)]
     pub certification_name: String,
+    #[max_len(MAX_NAME_LEN)]
     pub issuer: String,
     pub date_of_issue: i64,
+    #[max_len(MAX_LINK_LEN)]
     pub proof_link: Option<String>,
+    /// Verified status lapses once this timestamp passes.
+    pub expires_at: Option<i64>,
     pub timestamp: i64,
     pub status: VerificationStatus,
+    #[max_len(MAX_CREDENTIAL_VERIFIERS)]
     pub verifiers: Vec<Pubkey>,
+    pub index: u32,
+    pub bump: u8,
+    /// Bumped on every owner edit.
+    pub revision: u32,
 }
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+
+impl CertificateCredential {
+    pub fn validate(&self, now: i64) -> Result<()> {
+        require!(
+            self.certification_name.len() <= MAX_NAME_LEN,
+            TabluError::CertificationNameTooLong
+        );
+        require!(self.issuer.len() <= MAX_NAME_LEN, TabluError::IssuerTooLong);
+        require!(
+            self.proof_link.as_ref().map_or(0, String::len) <= MAX_LINK_LEN,
+            TabluError::ProofLinkTooLong
+        );
+        require!(
+            !self.certification_name.trim().is_empty(),
+            TabluError::EmptyName
+        );
+        require!(!self.issuer.trim().is_empty(), TabluError::EmptyName);
+        require!(
+            self.proof_link.iter().all(|link| is_well_formed_url(link)),
+            TabluError::InvalidUrl
+        );
+        require!(self.date_of_issue <= now, TabluError::DateInFuture);
+        validate_expiry(self.expires_at, self.date_of_issue)
+    }
+}
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum CredentialType {
     Degree,
     Project,
@@ -430,17 +1837,44 @@ pub enum CredentialType {
     Certificate,
 }
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum PaymentMethod {
+    Sol,
+    Token,
+}
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum VerificationStatus {
     Pending,
     Verified,
     Rejected,
+    Revoked,
+    Expired,
 }
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum RevocationReason {
+    Fraudulent,
+    IssuerRequest,
+    Superseded,
+    IssuedInError,
+    Other,
+}
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum ProficiencyLevel {
     Beginner,
     Intermediate,
     Advanced,
 }
+#[event]
+pub struct FeeConfigUpdated {
+    pub treasury: Pubkey,
+    pub fees: FeeSchedule,
+}
+
+#[event]
+pub struct TokenFeeConfigUpdated {
+    pub token_mint: Pubkey,
+    pub token_fees: FeeSchedule,
+}
+
 #[event]
 pub struct DegreeCredentialSubmitted {
     pub user: Pubkey,
@@ -475,4 +1909,327 @@ pub struct CertificateSubmitted {
     pub user: Pubkey,
     pub certification_name: String,
     pub timestamp:i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct CredentialUpdated {
+    pub user: Pubkey,
+    pub credential: Pubkey,
+    pub credential_type: CredentialType,
+    pub revision: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialWithdrawn {
+    pub user: Pubkey,
+    pub credential: Pubkey,
+    pub credential_type: CredentialType,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialRevoked {
+    pub credential: Pubkey,
+    pub credential_type: CredentialType,
+    pub authority: Pubkey,
+    pub reason: RevocationReason,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialExpired {
+    pub credential: Pubkey,
+    pub credential_type: CredentialType,
+    pub timestamp: i64,
+}
+
+#[error_code]
+pub enum TabluError {
+    #[msg("Only the program admin can perform this action")]
+    Unauthorized,
+    #[msg("Signer is not authorized to update verification status")]
+    UnauthorizedVerifier,
+    #[msg("Verifier is already authorized")]
+    VerifierAlreadyAuthorized,
+    #[msg("Verifier is not in the authorized set")]
+    VerifierNotFound,
+    #[msg("Treasury does not match the fee config")]
+    InvalidTreasury,
+    #[msg("Token mint does not match the fee config")]
+    InvalidTokenMint,
+    #[msg("Token fee payment is not enabled")]
+    TokenPaymentDisabled,
+    #[msg("Token payment requires the mint, token accounts and token program")]
+    MissingTokenAccounts,
+    #[msg("Maximum number of authorized verifiers reached")]
+    TooManyVerifiers,
+    #[msg("Maximum number of verifiers for this credential reached")]
+    TooManyCredentialVerifiers,
+    #[msg("Degree name is too long")]
+    DegreeNameTooLong,
+    #[msg("College name is too long")]
+    CollegeNameTooLong,
+    #[msg("Project name is too long")]
+    ProjectNameTooLong,
+    #[msg("Project description is too long")]
+    ProjectDescriptionTooLong,
+    #[msg("Too many collaborators")]
+    TooManyCollaborators,
+    #[msg("Collaborator name is too long")]
+    CollaboratorNameTooLong,
+    #[msg("Project link is too long")]
+    ProjectLinkTooLong,
+    #[msg("Skill name is too long")]
+    SkillNameTooLong,
+    #[msg("Proof link is too long")]
+    ProofLinkTooLong,
+    #[msg("Company name is too long")]
+    CompanyNameTooLong,
+    #[msg("Job title is too long")]
+    JobTitleTooLong,
+    #[msg("Certification name is too long")]
+    CertificationNameTooLong,
+    #[msg("Issuer name is too long")]
+    IssuerTooLong,
+    #[msg("Name fields cannot be empty")]
+    EmptyName,
+    #[msg("Passout year is out of range")]
+    InvalidPassoutYear,
+    #[msg("Date cannot be in the future")]
+    DateInFuture,
+    #[msg("End date cannot be before start date")]
+    EndBeforeStart,
+    #[msg("End date cannot be set while currently working")]
+    EndDateWhileCurrent,
+    #[msg("Link must be a well-formed http(s) URL")]
+    InvalidUrl,
+    #[msg("Only the credential owner can perform this action")]
+    NotCredentialOwner,
+    #[msg("Credential can only be edited while verification is pending")]
+    NotPending,
+    #[msg("Expiry must be after the credential's issue or start date")]
+    InvalidExpiry,
+    #[msg("Profile does not belong to the credential owner")]
+    ProfileMismatch,
+    #[msg("Only pending or verified credentials can be revoked")]
+    NotRevocable,
+    #[msg("Credential is not verified")]
+    NotVerified,
+    #[msg("Credential has not reached its expiry")]
+    NotExpired,
+    #[msg("Start or end date is out of range")]
+    InvalidPeriodYear,
+    #[msg("Revoked or expired credentials cannot change status")]
+    CredentialRetired,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program_data(upgrade_authority: Option<Pubkey>) -> ProgramData {
+        ProgramData {
+            slot: 0,
+            upgrade_authority_address: upgrade_authority,
+        }
+    }
+
+    #[test]
+    fn upgrade_authority_may_initialize_configs() {
+        let authority = Pubkey::new_unique();
+        assert!(is_upgrade_authority(
+            &program_data(Some(authority)),
+            &authority
+        ));
+    }
+
+    #[test]
+    fn other_signer_may_not_initialize_configs() {
+        assert!(!is_upgrade_authority(
+            &program_data(Some(Pubkey::new_unique())),
+            &Pubkey::new_unique()
+        ));
+    }
+
+    #[test]
+    fn immutable_program_rejects_every_signer() {
+        assert!(!is_upgrade_authority(
+            &program_data(None),
+            &Pubkey::new_unique()
+        ));
+        assert!(!is_upgrade_authority(
+            &program_data(None),
+            &Pubkey::default()
+        ));
+    }
+
+    fn program_config(verifiers: Vec<Pubkey>) -> ProgramConfig {
+        ProgramConfig {
+            admin: Pubkey::new_unique(),
+            verifier_authority: Pubkey::new_unique(),
+            verifiers,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn random_key_is_not_authorized() {
+        let config = program_config(vec![Pubkey::new_unique()]);
+        assert!(!config.is_authorized(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn verifier_authority_is_authorized() {
+        let config = program_config(Vec::new());
+        assert!(config.is_authorized(&config.verifier_authority));
+    }
+
+    #[test]
+    fn listed_verifier_is_authorized() {
+        let verifier = Pubkey::new_unique();
+        let config = program_config(vec![Pubkey::new_unique(), verifier]);
+        assert!(config.is_authorized(&verifier));
+    }
+
+    #[test]
+    fn removed_verifier_is_not_authorized() {
+        let verifier = Pubkey::new_unique();
+        let mut config = program_config(vec![verifier]);
+        assert!(config.is_authorized(&verifier));
+
+        config.remove_verifier(&verifier).unwrap();
+        assert!(!config.is_authorized(&verifier));
+    }
+
+    /// Mid-2024.
+    const NOW: i64 = 1_719_792_000;
+
+    fn year_start(year: i64) -> i64 {
+        (year - 1970) * SECONDS_PER_YEAR
+    }
+
+    #[test]
+    fn period_within_plausible_years_is_accepted() {
+        assert!(validate_period(year_start(1950), None, Some(true), NOW).is_ok());
+        assert!(validate_period(
+            year_start(2015),
+            Some(year_start(2034)),
+            None,
+            NOW
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn start_date_before_minimum_year_is_rejected() {
+        assert_eq!(
+            validate_period(year_start(1949), None, Some(true), NOW),
+            Err(TabluError::InvalidPeriodYear.into())
+        );
+        assert_eq!(
+            validate_period(i64::MIN, Some(year_start(2000)), None, NOW),
+            Err(TabluError::InvalidPeriodYear.into())
+        );
+    }
+
+    #[test]
+    fn end_date_past_maximum_year_is_rejected() {
+        assert_eq!(
+            validate_period(year_start(2015), Some(year_start(2035)), None, NOW),
+            Err(TabluError::InvalidPeriodYear.into())
+        );
+        assert_eq!(
+            validate_period(year_start(2015), Some(i64::MAX), None, NOW),
+            Err(TabluError::InvalidPeriodYear.into())
+        );
+    }
+
+    fn zeroed<T: AnchorDeserialize>(space: usize) -> T {
+        T::deserialize(&mut &vec![0u8; space][..]).unwrap()
+    }
+
+    #[test]
+    fn revoked_and_expired_credentials_are_final() {
+        let verifier = Pubkey::new_unique();
+        for retired in [VerificationStatus::Revoked, VerificationStatus::Expired] {
+            for new_status in [
+                VerificationStatus::Verified,
+                VerificationStatus::Rejected,
+                VerificationStatus::Pending,
+            ] {
+                let mut profile: UserProfile = zeroed(UserProfile::INIT_SPACE);
+                let mut status = retired.clone();
+                let mut verifiers = Vec::new();
+                assert_eq!(
+                    record_verification(
+                        &mut profile,
+                        CredentialType::Degree,
+                        &mut status,
+                        &mut verifiers,
+                        new_status,
+                        vec![verifier],
+                    ),
+                    Err(TabluError::CredentialRetired.into())
+                );
+                assert_eq!(status, retired);
+                assert!(verifiers.is_empty());
+                assert_eq!(profile.verified_degree_count, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn pending_credential_takes_an_outcome() {
+        let mut profile: UserProfile = zeroed(UserProfile::INIT_SPACE);
+        let mut status = VerificationStatus::Pending;
+        let mut verifiers = Vec::new();
+        record_verification(
+            &mut profile,
+            CredentialType::Degree,
+            &mut status,
+            &mut verifiers,
+            VerificationStatus::Verified,
+            vec![Pubkey::new_unique()],
+        )
+        .unwrap();
+        assert_eq!(status, VerificationStatus::Verified);
+        assert_eq!(verifiers.len(), 1);
+        assert_eq!(profile.verified_degree_count, 1);
+    }
+
+    #[test]
+    fn expiry_must_follow_the_effective_date() {
+        assert!(validate_expiry(None, NOW).is_ok());
+        assert!(validate_expiry(Some(NOW + 1), NOW).is_ok());
+        assert_eq!(
+            validate_expiry(Some(NOW), NOW),
+            Err(TabluError::InvalidExpiry.into())
+        );
+    }
+
+    #[test]
+    fn every_credential_type_lapses_at_its_expiry() {
+        fn lapses<T: CredentialAccount>(mut credential: T) {
+            credential.set_status(VerificationStatus::Verified);
+            assert!(credential.is_currently_valid(NOW - 1));
+            assert!(!credential.is_currently_valid(NOW));
+        }
+
+        let mut degree: UserDegreeCredential = zeroed(UserDegreeCredential::INIT_SPACE);
+        degree.expires_at = Some(NOW);
+        lapses(degree);
+        let mut project: ProjectCredential = zeroed(ProjectCredential::INIT_SPACE);
+        project.expires_at = Some(NOW);
+        lapses(project);
+        let mut skill: SkillCredential = zeroed(SkillCredential::INIT_SPACE);
+        skill.expires_at = Some(NOW);
+        lapses(skill);
+        let mut employment: EmploymentCredential = zeroed(EmploymentCredential::INIT_SPACE);
+        employment.expires_at = Some(NOW);
+        lapses(employment);
+        let mut certificate: CertificateCredential = zeroed(CertificateCredential::INIT_SPACE);
+        certificate.expires_at = Some(NOW);
+        lapses(certificate);
+    }
+}
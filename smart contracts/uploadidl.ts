@@ -5,71 +5,222 @@ export const IDL1 = {
     {
       name: "submitDegree",
       accounts: [
-        { name: "credential", isMut: true, isSigner: true },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "credential", isMut: true, isSigner: false },
         { name: "user", isMut: true, isSigner: true },
-        { name: "treasury", isMut: true, isSigner: false },
+        {
+          name: "fee",
+          accounts: [
+            { name: "feeConfig", isMut: false, isSigner: false },
+            { name: "treasury", isMut: true, isSigner: false },
+            {
+              name: "tokenMint",
+              isMut: false,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "userTokenAccount",
+              isMut: true,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "treasuryTokenAccount",
+              isMut: true,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "tokenProgram",
+              isMut: false,
+              isSigner: false,
+              isOptional: true,
+            },
+          ],
+        },
         { name: "systemProgram", isMut: false, isSigner: false },
       ],
       args: [
         { name: "degreeName", type: "string" },
         { name: "collegeName", type: "string" },
         { name: "passoutYear", type: "i64" },
+        { name: "expiresAt", type: { option: "i64" } },
+        { name: "paymentMethod", type: { defined: "PaymentMethod" } },
       ],
     },
     {
       name: "submitProject",
       accounts: [
-        { name: "project", isMut: true, isSigner: true },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "project", isMut: true, isSigner: false },
         { name: "user", isMut: true, isSigner: true },
-        { name: "treasury", isMut: true, isSigner: false },
+        {
+          name: "fee",
+          accounts: [
+            { name: "feeConfig", isMut: false, isSigner: false },
+            { name: "treasury", isMut: true, isSigner: false },
+            {
+              name: "tokenMint",
+              isMut: false,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "userTokenAccount",
+              isMut: true,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "treasuryTokenAccount",
+              isMut: true,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "tokenProgram",
+              isMut: false,
+              isSigner: false,
+              isOptional: true,
+            },
+          ],
+        },
         { name: "systemProgram", isMut: false, isSigner: false },
       ],
       args: [
-        { name: "projectName", type: "string" },
-        { name: "projectDescription", type: "string" },
-        { name: "collaborators", type: { option: { vec: "string" } } },
-        { name: "startDate", type: "i32" },
-        { name: "endDate", type: { option: "i32" } },
-        { name: "currentlyWorking", type: { option: "bool" } },
-        { name: "projectLink", type: "string" },
+        { name: "details", type: { defined: "ProjectDetails" } },
+        { name: "paymentMethod", type: { defined: "PaymentMethod" } },
       ],
     },
     {
       name: "submitSkill",
       accounts: [
-        { name: "skill", isMut: true, isSigner: true },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "skill", isMut: true, isSigner: false },
         { name: "user", isMut: true, isSigner: true },
-        { name: "treasury", isMut: true, isSigner: false },
+        {
+          name: "fee",
+          accounts: [
+            { name: "feeConfig", isMut: false, isSigner: false },
+            { name: "treasury", isMut: true, isSigner: false },
+            {
+              name: "tokenMint",
+              isMut: false,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "userTokenAccount",
+              isMut: true,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "treasuryTokenAccount",
+              isMut: true,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "tokenProgram",
+              isMut: false,
+              isSigner: false,
+              isOptional: true,
+            },
+          ],
+        },
         { name: "systemProgram", isMut: false, isSigner: false },
       ],
       args: [
         { name: "skillName", type: "string" },
         { name: "proficiencyLevel", type: { defined: "ProficiencyLevel" } },
         { name: "proofLink", type: "string" },
+        { name: "expiresAt", type: { option: "i64" } },
+        { name: "paymentMethod", type: { defined: "PaymentMethod" } },
       ],
     },
     {
       name: "submitEmployment",
       accounts: [
-        { name: "employment", isMut: true, isSigner: true },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "employment", isMut: true, isSigner: false },
         { name: "user", isMut: true, isSigner: true },
-        { name: "treasury", isMut: true, isSigner: false },
+        {
+          name: "fee",
+          accounts: [
+            { name: "feeConfig", isMut: false, isSigner: false },
+            { name: "treasury", isMut: true, isSigner: false },
+            {
+              name: "tokenMint",
+              isMut: false,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "userTokenAccount",
+              isMut: true,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "treasuryTokenAccount",
+              isMut: true,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "tokenProgram",
+              isMut: false,
+              isSigner: false,
+              isOptional: true,
+            },
+          ],
+        },
         { name: "systemProgram", isMut: false, isSigner: false },
       ],
       args: [
-        { name: "companyName", type: "string" },
-        { name: "jobTitle", type: "string" },
-        { name: "startDate", type: "i64" },
-        { name: "endDate", type: { option: "i64" } },
-        { name: "currentlyWorking", type: { option: "bool" } },
+        { name: "details", type: { defined: "EmploymentDetails" } },
+        { name: "paymentMethod", type: { defined: "PaymentMethod" } },
       ],
     },
     {
       name: "submitCertificate",
       accounts: [
-        { name: "certificate", isMut: true, isSigner: true },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "certificate", isMut: true, isSigner: false },
         { name: "user", isMut: true, isSigner: true },
-        { name: "treasury", isMut: true, isSigner: false },
+        {
+          name: "fee",
+          accounts: [
+            { name: "feeConfig", isMut: false, isSigner: false },
+            { name: "treasury", isMut: true, isSigner: false },
+            {
+              name: "tokenMint",
+              isMut: false,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "userTokenAccount",
+              isMut: true,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "treasuryTokenAccount",
+              isMut: true,
+              isSigner: false,
+              isOptional: true,
+            },
+            {
+              name: "tokenProgram",
+              isMut: false,
+              isSigner: false,
+              isOptional: true,
+            },
+          ],
+        },
         { name: "systemProgram", isMut: false, isSigner: false },
       ],
       args: [
@@ -77,50 +228,323 @@ export const IDL1 = {
         { name: "issuer", type: "string" },
         { name: "dateOfIssue", type: "i64" },
         { name: "proofLink", type: { option: "string" } },
+        { name: "expiresAt", type: { option: "i64" } },
+        { name: "paymentMethod", type: { defined: "PaymentMethod" } },
+      ],
+    },
+    {
+      name: "updateDegree",
+      accounts: [
+        { name: "credential", isMut: true, isSigner: false },
+        { name: "user", isMut: false, isSigner: true },
+      ],
+      args: [
+        { name: "degreeName", type: "string" },
+        { name: "collegeName", type: "string" },
+        { name: "passoutYear", type: "i64" },
+        { name: "expiresAt", type: { option: "i64" } },
+      ],
+    },
+    {
+      name: "updateProject",
+      accounts: [
+        { name: "project", isMut: true, isSigner: false },
+        { name: "user", isMut: false, isSigner: true },
+      ],
+      args: [{ name: "details", type: { defined: "ProjectDetails" } }],
+    },
+    {
+      name: "updateSkill",
+      accounts: [
+        { name: "skill", isMut: true, isSigner: false },
+        { name: "user", isMut: false, isSigner: true },
+      ],
+      args: [
+        { name: "skillName", type: "string" },
+        { name: "proficiencyLevel", type: { defined: "ProficiencyLevel" } },
+        { name: "proofLink", type: "string" },
+        { name: "expiresAt", type: { option: "i64" } },
+      ],
+    },
+    {
+      name: "updateEmployment",
+      accounts: [
+        { name: "employment", isMut: true, isSigner: false },
+        { name: "user", isMut: false, isSigner: true },
+      ],
+      args: [{ name: "details", type: { defined: "EmploymentDetails" } }],
+    },
+    {
+      name: "updateCertificate",
+      accounts: [
+        { name: "certificate", isMut: true, isSigner: false },
+        { name: "user", isMut: false, isSigner: true },
+      ],
+      args: [
+        { name: "certificationName", type: "string" },
+        { name: "issuer", type: "string" },
+        { name: "dateOfIssue", type: "i64" },
+        { name: "proofLink", type: { option: "string" } },
+        { name: "expiresAt", type: { option: "i64" } },
+      ],
+    },
+    {
+      name: "closeDegree",
+      accounts: [
+        { name: "credential", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "user", isMut: true, isSigner: true },
+      ],
+      args: [],
+    },
+    {
+      name: "closeProject",
+      accounts: [
+        { name: "project", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "user", isMut: true, isSigner: true },
+      ],
+      args: [],
+    },
+    {
+      name: "closeSkill",
+      accounts: [
+        { name: "skill", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "user", isMut: true, isSigner: true },
+      ],
+      args: [],
+    },
+    {
+      name: "closeEmployment",
+      accounts: [
+        { name: "employment", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "user", isMut: true, isSigner: true },
+      ],
+      args: [],
+    },
+    {
+      name: "closeCertificate",
+      accounts: [
+        { name: "certificate", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "user", isMut: true, isSigner: true },
+      ],
+      args: [],
+    },
+    {
+      name: "initializeConfig",
+      accounts: [
+        { name: "config", isMut: true, isSigner: false },
+        { name: "admin", isMut: true, isSigner: true },
+        { name: "program", isMut: false, isSigner: false },
+        { name: "programData", isMut: false, isSigner: false },
+        { name: "systemProgram", isMut: false, isSigner: false },
+      ],
+      args: [{ name: "verifierAuthority", type: "publicKey" }],
+    },
+    {
+      name: "setVerifierAuthority",
+      accounts: [
+        { name: "config", isMut: true, isSigner: false },
+        { name: "admin", isMut: false, isSigner: true },
+      ],
+      args: [{ name: "verifierAuthority", type: "publicKey" }],
+    },
+    {
+      name: "addVerifier",
+      accounts: [
+        { name: "config", isMut: true, isSigner: false },
+        { name: "admin", isMut: false, isSigner: true },
+      ],
+      args: [{ name: "verifier", type: "publicKey" }],
+    },
+    {
+      name: "removeVerifier",
+      accounts: [
+        { name: "config", isMut: true, isSigner: false },
+        { name: "admin", isMut: false, isSigner: true },
+      ],
+      args: [{ name: "verifier", type: "publicKey" }],
+    },
+    {
+      name: "initializeFeeConfig",
+      accounts: [
+        { name: "feeConfig", isMut: true, isSigner: false },
+        { name: "admin", isMut: true, isSigner: true },
+        { name: "program", isMut: false, isSigner: false },
+        { name: "programData", isMut: false, isSigner: false },
+        { name: "systemProgram", isMut: false, isSigner: false },
+      ],
+      args: [
+        { name: "treasury", type: "publicKey" },
+        { name: "fees", type: { defined: "FeeSchedule" } },
+      ],
+    },
+    {
+      name: "updateFeeConfig",
+      accounts: [
+        { name: "feeConfig", isMut: true, isSigner: false },
+        { name: "admin", isMut: false, isSigner: true },
+      ],
+      args: [
+        { name: "treasury", type: "publicKey" },
+        { name: "fees", type: { defined: "FeeSchedule" } },
+      ],
+    },
+    {
+      name: "updateTokenFeeConfig",
+      accounts: [
+        { name: "feeConfig", isMut: true, isSigner: false },
+        { name: "admin", isMut: false, isSigner: true },
+      ],
+      args: [
+        { name: "tokenMint", type: "publicKey" },
+        { name: "tokenFees", type: { defined: "FeeSchedule" } },
       ],
     },
     {
       name: "updateDegreeVerificationStatus",
       accounts: [
         { name: "credential", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "config", isMut: false, isSigner: false },
         { name: "authority", isMut: false, isSigner: true },
       ],
-      args: [{ name: "newStatus", type: { defined: "VerificationStatus" } }],
+      args: [
+        { name: "newStatus", type: { defined: "VerificationStatus" } },
+        { name: "verifiers", type: { vec: "publicKey" } },
+      ],
     },
     {
       name: "updateProjectVerificationStatus",
       accounts: [
         { name: "credential", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "config", isMut: false, isSigner: false },
         { name: "authority", isMut: false, isSigner: true },
       ],
-      args: [{ name: "newStatus", type: { defined: "VerificationStatus" } }],
+      args: [
+        { name: "newStatus", type: { defined: "VerificationStatus" } },
+        { name: "verifiers", type: { vec: "publicKey" } },
+      ],
     },
     {
       name: "updateSkillVerificationStatus",
       accounts: [
         { name: "credential", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "config", isMut: false, isSigner: false },
         { name: "authority", isMut: false, isSigner: true },
       ],
-      args: [{ name: "newStatus", type: { defined: "VerificationStatus" } }],
+      args: [
+        { name: "newStatus", type: { defined: "VerificationStatus" } },
+        { name: "verifiers", type: { vec: "publicKey" } },
+      ],
     },
     {
       name: "updateEmploymentVerificationStatus",
       accounts: [
         { name: "credential", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "config", isMut: false, isSigner: false },
         { name: "authority", isMut: false, isSigner: true },
       ],
-      args: [{ name: "newStatus", type: { defined: "VerificationStatus" } }],
+      args: [
+        { name: "newStatus", type: { defined: "VerificationStatus" } },
+        { name: "verifiers", type: { vec: "publicKey" } },
+      ],
     },
     {
       name: "updateCertificateVerificationStatus",
       accounts: [
         { name: "credential", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "config", isMut: false, isSigner: false },
         { name: "authority", isMut: false, isSigner: true },
       ],
-      args: [{ name: "newStatus", type: { defined: "VerificationStatus" } }],
+      args: [
+        { name: "newStatus", type: { defined: "VerificationStatus" } },
+        { name: "verifiers", type: { vec: "publicKey" } },
+      ],
+    },
+    {
+      name: "revokeCredential",
+      accounts: [
+        { name: "credential", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+        { name: "config", isMut: false, isSigner: false },
+        { name: "authority", isMut: false, isSigner: true },
+      ],
+      args: [
+        { name: "credentialType", type: { defined: "CredentialType" } },
+        { name: "reason", type: { defined: "RevocationReason" } },
+      ],
+    },
+    {
+      name: "expireCredential",
+      accounts: [
+        { name: "credential", isMut: true, isSigner: false },
+        { name: "profile", isMut: true, isSigner: false },
+      ],
+      args: [{ name: "credentialType", type: { defined: "CredentialType" } }],
+    },
+    {
+      name: "isCurrentlyValid",
+      accounts: [{ name: "credential", isMut: false, isSigner: false }],
+      args: [{ name: "credentialType", type: { defined: "CredentialType" } }],
+      returns: "bool",
     },
   ],
   accounts: [
+    {
+      name: "ProgramConfig",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "admin", type: "publicKey" },
+          { name: "verifierAuthority", type: "publicKey" },
+          { name: "verifiers", type: { vec: "publicKey" } },
+          { name: "bump", type: "u8" },
+        ],
+      },
+    },
+    {
+      name: "FeeConfig",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "admin", type: "publicKey" },
+          { name: "treasury", type: "publicKey" },
+          { name: "fees", type: { defined: "FeeSchedule" } },
+          { name: "tokenMint", type: "publicKey" },
+          { name: "tokenFees", type: { defined: "FeeSchedule" } },
+          { name: "bump", type: "u8" },
+        ],
+      },
+    },
+    {
+      name: "UserProfile",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "user", type: "publicKey" },
+          { name: "degreeCount", type: "u32" },
+          { name: "projectCount", type: "u32" },
+          { name: "skillCount", type: "u32" },
+          { name: "employmentCount", type: "u32" },
+          { name: "certificateCount", type: "u32" },
+          { name: "verifiedDegreeCount", type: "u32" },
+          { name: "verifiedProjectCount", type: "u32" },
+          { name: "verifiedSkillCount", type: "u32" },
+          { name: "verifiedEmploymentCount", type: "u32" },
+          { name: "verifiedCertificateCount", type: "u32" },
+          { name: "lastSubmissionAt", type: "i64" },
+          { name: "bump", type: "u8" },
+        ],
+      },
+    },
     {
       name: "UserDegreeCredential",
       type: {
@@ -130,9 +554,13 @@ export const IDL1 = {
           { name: "degreeName", type: "string" },
           { name: "collegeName", type: "string" },
           { name: "passoutYear", type: "i64" },
+          { name: "expiresAt", type: { option: "i64" } },
           { name: "status", type: { defined: "VerificationStatus" } },
           { name: "timestamp", type: "i64" },
           { name: "verifiers", type: { vec: "publicKey" } },
+          { name: "index", type: "u32" },
+          { name: "bump", type: "u8" },
+          { name: "revision", type: "u32" },
         ],
       },
     },
@@ -149,9 +577,13 @@ export const IDL1 = {
           { name: "endDate", type: { option: "i32" } },
           { name: "currentlyWorking", type: { option: "bool" } },
           { name: "projectLink", type: "string" },
+          { name: "expiresAt", type: { option: "i64" } },
           { name: "timestamp", type: "i32" },
           { name: "status", type: { defined: "VerificationStatus" } },
           { name: "verifiers", type: { vec: "publicKey" } },
+          { name: "index", type: "u32" },
+          { name: "bump", type: "u8" },
+          { name: "revision", type: "u32" },
         ],
       },
     },
@@ -164,9 +596,13 @@ export const IDL1 = {
           { name: "skillName", type: "string" },
           { name: "proficiencyLevel", type: { defined: "ProficiencyLevel" } },
           { name: "proofLink", type: "string" },
+          { name: "expiresAt", type: { option: "i64" } },
           { name: "timestamp", type: "i64" },
           { name: "status", type: { defined: "VerificationStatus" } },
           { name: "verifiers", type: { vec: "publicKey" } },
+          { name: "index", type: "u32" },
+          { name: "bump", type: "u8" },
+          { name: "revision", type: "u32" },
         ],
       },
     },
@@ -181,9 +617,13 @@ export const IDL1 = {
           { name: "startDate", type: "i64" },
           { name: "endDate", type: { option: "i64" } },
           { name: "currentlyWorking", type: { option: "bool" } },
+          { name: "expiresAt", type: { option: "i64" } },
           { name: "timestamp", type: "i64" },
           { name: "status", type: { defined: "VerificationStatus" } },
           { name: "verifiers", type: { vec: "publicKey" } },
+          { name: "index", type: "u32" },
+          { name: "bump", type: "u8" },
+          { name: "revision", type: "u32" },
         ],
       },
     },
@@ -197,14 +637,61 @@ export const IDL1 = {
           { name: "issuer", type: "string" },
           { name: "dateOfIssue", type: "i64" },
           { name: "proofLink", type: { option: "string" } },
+          { name: "expiresAt", type: { option: "i64" } },
           { name: "timestamp", type: "i64" },
           { name: "status", type: { defined: "VerificationStatus" } },
           { name: "verifiers", type: { vec: "publicKey" } },
+          { name: "index", type: "u32" },
+          { name: "bump", type: "u8" },
+          { name: "revision", type: "u32" },
         ],
       },
     },
   ],
   types: [
+    {
+      name: "FeeSchedule",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "degree", type: "u64" },
+          { name: "project", type: "u64" },
+          { name: "skill", type: "u64" },
+          { name: "employment", type: "u64" },
+          { name: "certificate", type: "u64" },
+        ],
+      },
+    },
+    {
+      name: "ProjectDetails",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "projectName", type: "string" },
+          { name: "projectDescription", type: "string" },
+          { name: "collaborators", type: { option: { vec: "string" } } },
+          { name: "startDate", type: "i32" },
+          { name: "endDate", type: { option: "i32" } },
+          { name: "currentlyWorking", type: { option: "bool" } },
+          { name: "projectLink", type: "string" },
+          { name: "expiresAt", type: { option: "i64" } },
+        ],
+      },
+    },
+    {
+      name: "EmploymentDetails",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "companyName", type: "string" },
+          { name: "jobTitle", type: "string" },
+          { name: "startDate", type: "i64" },
+          { name: "endDate", type: { option: "i64" } },
+          { name: "currentlyWorking", type: { option: "bool" } },
+          { name: "expiresAt", type: { option: "i64" } },
+        ],
+      },
+    },
     {
       name: "CredentialType",
       type: {
@@ -218,6 +705,10 @@ export const IDL1 = {
         ],
       },
     },
+    {
+      name: "PaymentMethod",
+      type: { kind: "enum", variants: [{ name: "Sol" }, { name: "Token" }] },
+    },
     {
       name: "VerificationStatus",
       type: {
@@ -226,6 +717,21 @@ export const IDL1 = {
           { name: "Pending" },
           { name: "Verified" },
           { name: "Rejected" },
+          { name: "Revoked" },
+          { name: "Expired" },
+        ],
+      },
+    },
+    {
+      name: "RevocationReason",
+      type: {
+        kind: "enum",
+        variants: [
+          { name: "Fraudulent" },
+          { name: "IssuerRequest" },
+          { name: "Superseded" },
+          { name: "IssuedInError" },
+          { name: "Other" },
         ],
       },
     },
@@ -242,6 +748,20 @@ export const IDL1 = {
     },
   ],
   events: [
+    {
+      name: "FeeConfigUpdated",
+      fields: [
+        { name: "treasury", type: "publicKey", index: false },
+        { name: "fees", type: { defined: "FeeSchedule" }, index: false },
+      ],
+    },
+    {
+      name: "TokenFeeConfigUpdated",
+      fields: [
+        { name: "tokenMint", type: "publicKey", index: false },
+        { name: "tokenFees", type: { defined: "FeeSchedule" }, index: false },
+      ],
+    },
     {
       name: "DegreeCredentialSubmitted",
       fields: [
@@ -284,5 +804,198 @@ export const IDL1 = {
         { name: "timestamp", type: "i64", index: false },
       ],
     },
+    {
+      name: "CredentialUpdated",
+      fields: [
+        { name: "user", type: "publicKey", index: false },
+        { name: "credential", type: "publicKey", index: false },
+        {
+          name: "credentialType",
+          type: { defined: "CredentialType" },
+          index: false,
+        },
+        { name: "revision", type: "u32", index: false },
+        { name: "timestamp", type: "i64", index: false },
+      ],
+    },
+    {
+      name: "CredentialWithdrawn",
+      fields: [
+        { name: "user", type: "publicKey", index: false },
+        { name: "credential", type: "publicKey", index: false },
+        {
+          name: "credentialType",
+          type: { defined: "CredentialType" },
+          index: false,
+        },
+        { name: "timestamp", type: "i64", index: false },
+      ],
+    },
+    {
+      name: "CredentialRevoked",
+      fields: [
+        { name: "credential", type: "publicKey", index: false },
+        {
+          name: "credentialType",
+          type: { defined: "CredentialType" },
+          index: false,
+        },
+        { name: "authority", type: "publicKey", index: false },
+        { name: "reason", type: { defined: "RevocationReason" }, index: false },
+        { name: "timestamp", type: "i64", index: false },
+      ],
+    },
+    {
+      name: "CredentialExpired",
+      fields: [
+        { name: "credential", type: "publicKey", index: false },
+        {
+          name: "credentialType",
+          type: { defined: "CredentialType" },
+          index: false,
+        },
+        { name: "timestamp", type: "i64", index: false },
+      ],
+    },
+  ],
+  errors: [
+    {
+      code: 6000,
+      name: "Unauthorized",
+      msg: "Only the program admin can perform this action",
+    },
+    {
+      code: 6001,
+      name: "UnauthorizedVerifier",
+      msg: "Signer is not authorized to update verification status",
+    },
+    {
+      code: 6002,
+      name: "VerifierAlreadyAuthorized",
+      msg: "Verifier is already authorized",
+    },
+    {
+      code: 6003,
+      name: "VerifierNotFound",
+      msg: "Verifier is not in the authorized set",
+    },
+    {
+      code: 6004,
+      name: "InvalidTreasury",
+      msg: "Treasury does not match the fee config",
+    },
+    {
+      code: 6005,
+      name: "InvalidTokenMint",
+      msg: "Token mint does not match the fee config",
+    },
+    {
+      code: 6006,
+      name: "TokenPaymentDisabled",
+      msg: "Token fee payment is not enabled",
+    },
+    {
+      code: 6007,
+      name: "MissingTokenAccounts",
+      msg: "Token payment requires the mint, token accounts and token program",
+    },
+    {
+      code: 6008,
+      name: "TooManyVerifiers",
+      msg: "Maximum number of authorized verifiers reached",
+    },
+    {
+      code: 6009,
+      name: "TooManyCredentialVerifiers",
+      msg: "Maximum number of verifiers for this credential reached",
+    },
+    { code: 6010, name: "DegreeNameTooLong", msg: "Degree name is too long" },
+    { code: 6011, name: "CollegeNameTooLong", msg: "College name is too long" },
+    { code: 6012, name: "ProjectNameTooLong", msg: "Project name is too long" },
+    {
+      code: 6013,
+      name: "ProjectDescriptionTooLong",
+      msg: "Project description is too long",
+    },
+    { code: 6014, name: "TooManyCollaborators", msg: "Too many collaborators" },
+    {
+      code: 6015,
+      name: "CollaboratorNameTooLong",
+      msg: "Collaborator name is too long",
+    },
+    { code: 6016, name: "ProjectLinkTooLong", msg: "Project link is too long" },
+    { code: 6017, name: "SkillNameTooLong", msg: "Skill name is too long" },
+    { code: 6018, name: "ProofLinkTooLong", msg: "Proof link is too long" },
+    { code: 6019, name: "CompanyNameTooLong", msg: "Company name is too long" },
+    { code: 6020, name: "JobTitleTooLong", msg: "Job title is too long" },
+    {
+      code: 6021,
+      name: "CertificationNameTooLong",
+      msg: "Certification name is too long",
+    },
+    { code: 6022, name: "IssuerTooLong", msg: "Issuer name is too long" },
+    { code: 6023, name: "EmptyName", msg: "Name fields cannot be empty" },
+    {
+      code: 6024,
+      name: "InvalidPassoutYear",
+      msg: "Passout year is out of range",
+    },
+    { code: 6025, name: "DateInFuture", msg: "Date cannot be in the future" },
+    {
+      code: 6026,
+      name: "EndBeforeStart",
+      msg: "End date cannot be before start date",
+    },
+    {
+      code: 6027,
+      name: "EndDateWhileCurrent",
+      msg: "End date cannot be set while currently working",
+    },
+    {
+      code: 6028,
+      name: "InvalidUrl",
+      msg: "Link must be a well-formed http(s) URL",
+    },
+    {
+      code: 6029,
+      name: "NotCredentialOwner",
+      msg: "Only the credential owner can perform this action",
+    },
+    {
+      code: 6030,
+      name: "NotPending",
+      msg: "Credential can only be edited while verification is pending",
+    },
+    {
+      code: 6031,
+      name: "InvalidExpiry",
+      msg: "Expiry must be after the credential's issue or start date",
+    },
+    {
+      code: 6032,
+      name: "ProfileMismatch",
+      msg: "Profile does not belong to the credential owner",
+    },
+    {
+      code: 6033,
+      name: "NotRevocable",
+      msg: "Only pending or verified credentials can be revoked",
+    },
+    { code: 6034, name: "NotVerified", msg: "Credential is not verified" },
+    {
+      code: 6035,
+      name: "NotExpired",
+      msg: "Credential has not reached its expiry",
+    },
+    {
+      code: 6036,
+      name: "InvalidPeriodYear",
+      msg: "Start or end date is out of range",
+    },
+    {
+      code: 6037,
+      name: "CredentialRetired",
+      msg: "Revoked or expired credentials cannot change status",
+    },
   ],
 } as const;
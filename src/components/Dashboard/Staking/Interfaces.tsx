@@ -6,13 +6,21 @@ export interface StakingState {
 }
 
 export interface CredentialState {
-  verifierCount: number;
-  isFinalized: boolean;
+  stakerCount: number;
+  quorum: number;
+  commitCount: number;
+  voteCount: number;
   authenticVotes: number;
-  createdAt: number;
+  isFinalized: boolean;
+  isCancelled: boolean;
+  outcome: "undecided" | "authentic" | "notAuthentic" | "tie";
+  stakingDeadline: number;
+  votingDeadline: number;
+  revealDeadline: number;
 }
 
 export interface VerifierInfo {
+  hasCommitted: boolean;
   hasVoted: boolean;
   hasClaimed: boolean;
   votedAuthentic: boolean;
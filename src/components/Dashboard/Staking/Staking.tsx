@@ -3,7 +3,6 @@ import { useWallet } from "@solana/wallet-adapter-react";
 import { Connection } from "@solana/web3.js";
 import { AnchorProvider, Program, web3, BN } from "@project-serum/anchor";
 import { toast } from "react-hot-toast";
-import { utf8 } from "@project-serum/anchor/dist/cjs/utils/bytes";
 import { InfoCircleOutlined } from "@ant-design/icons";
 import { Tooltip } from "antd";
 import {
@@ -18,13 +17,76 @@ import { fetchUnverifiedCredentials } from "../../../utils/allCredentialUtils";
 import CredentialCard from "../Profile/CredentialCard/CredentialCard";
 import "./Staking.css";
 import { IDL } from "../../../../smart contracts/stakeidl";
-import { IDL1 } from "../../../../smart contracts/uploadidl";
 import { Credential } from "./Credential";
 
 const CACHE_DURATION = 2000;
 const BATCH_SIZE = 2;
 const BATCH_DELAY = 2000;
 const STAKE_AMOUNT = 0.1 * web3.LAMPORTS_PER_SOL;
+const DAY_IN_SECS = 24 * 60 * 60;
+const TABLU_PROGRAM_ID = new web3.PublicKey(
+  "apwW9Vqxtu4Ga2dQ4R91jyYtWZ9HUFtx13MmPPfwLEb"
+);
+
+// Rounds opened from this page: ten equal-stake verifiers and a simple
+// majority, with each phase of the round lasting up to five days.
+const DEFAULT_ROUND_PARAMS = {
+  votingMode: { equal: {} },
+  minStake: new BN(STAKE_AMOUNT),
+  maxStake: new BN(STAKE_AMOUNT),
+  quorum: 10,
+  majorityBps: 5001,
+  tiePolicy: { refundAll: {} },
+  stakingWindow: new BN(5 * DAY_IN_SECS),
+  votingWindow: new BN(5 * DAY_IN_SECS),
+  revealWindow: new BN(5 * DAY_IN_SECS),
+  claimWindow: new BN(30 * DAY_IN_SECS),
+  minReputation: 0,
+  poolTag: "",
+  appealWindow: new BN(0),
+};
+
+const CREDENTIAL_TYPES: Record<CredentialType, object> = {
+  Degree: { degree: {} },
+  "Employment History": { employment: {} },
+  Project: { project: {} },
+  Certificate: { certificate: {} },
+  Skill: { skill: {} },
+};
+
+// Mirrors `pool_type_seed`, the discriminant tablu's `CredentialType` has
+// on chain.
+const CREDENTIAL_TYPE_SEEDS: Record<CredentialType, number> = {
+  Degree: 0,
+  Project: 1,
+  Skill: 2,
+  "Employment History": 3,
+  Certificate: 4,
+};
+
+// The salt must survive a reload between committing and revealing a vote.
+const voteStorageKey = (
+  credentialPDA: web3.PublicKey,
+  roundId: BN,
+  authority: web3.PublicKey
+) => `vote:${credentialPDA.toBase58()}:${roundId.toString()}:${authority}`;
+
+const voteCommitment = async (
+  credentialPDA: web3.PublicKey,
+  authority: web3.PublicKey,
+  isAuthentic: boolean,
+  salt: Uint8Array
+) => {
+  const preimage = Buffer.concat([
+    credentialPDA.toBuffer(),
+    authority.toBuffer(),
+    Buffer.from([isAuthentic ? 1 : 0]),
+    Buffer.from(salt),
+  ]);
+  return Array.from(
+    new Uint8Array(await crypto.subtle.digest("SHA-256", preimage))
+  );
+};
 
 const RPC_ENDPOINTS = {
   SOLANA: "https://api.devnet.solana.com",
@@ -257,18 +319,83 @@ const Staking: React.FC = () => {
   const getStakingProgress = (credentialId: string) => {
     const state = credentialStates[credentialId];
     if (!state) return 0;
-    return Math.min((state.verifierCount / 10) * 100, 100);
+    return Math.min((state.stakerCount / state.quorum) * 100, 100);
+  };
+
+  const findCredential = (credentialId: string) =>
+    unverifiedCredentials.find((cred) => cred.id === credentialId);
+
+  const getTargetCredential = (credentialId: string) => {
+    const credential = findCredential(credentialId);
+    if (!credential?.publicKey) {
+      throw new Error(`Unknown credential ${credentialId}`);
+    }
+    return new web3.PublicKey(credential.publicKey);
   };
 
   const deriveCredentialPDA = (program: Program, credentialId: string) => {
-    const credentialSeed = utf8.encode(credentialId);
     const [credentialPDA] = web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("credential"), credentialSeed],
+      [Buffer.from("credential"), getTargetCredential(credentialId).toBuffer()],
       program.programId
     );
     return credentialPDA;
   };
 
+  const deriveVaultPDA = (program: Program, credentialPDA: web3.PublicKey) => {
+    const [vaultPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), credentialPDA.toBuffer()],
+      program.programId
+    );
+    return vaultPDA;
+  };
+
+  const deriveVerifierPDA = (
+    program: Program,
+    credentialPDA: web3.PublicKey,
+    roundId: BN,
+    authority: web3.PublicKey
+  ) => {
+    const [verifierPDA] = web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("verifier"),
+        credentialPDA.toBuffer(),
+        roundId.toArrayLike(Buffer, "le", 8),
+        authority.toBuffer(),
+      ],
+      program.programId
+    );
+    return verifierPDA;
+  };
+
+  const deriveReputationPDA = (
+    program: Program,
+    authority: web3.PublicKey
+  ) => {
+    const [reputationPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reputation"), authority.toBuffer()],
+      program.programId
+    );
+    return reputationPDA;
+  };
+
+  const deriveConfigPDA = (program: Program) => {
+    const [configPDA] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("config")],
+      program.programId
+    );
+    return configPDA;
+  };
+
+  // Verifier accounts are keyed by the round, so look the round up first.
+  const fetchRound = async (program: Program, credentialId: string) => {
+    const credentialPDA = deriveCredentialPDA(program, credentialId);
+    const account: any = await program.account.credential.fetch(credentialPDA);
+    const verifierPDA = publicKey
+      ? deriveVerifierPDA(program, credentialPDA, account.roundId, publicKey)
+      : null;
+    return { credentialPDA, account, verifierPDA };
+  };
+
   const checkCredentialAccountExists = async (
     credentialPDA: web3.PublicKey
   ) => {
@@ -284,7 +411,8 @@ const Staking: React.FC = () => {
 
   const initializeCredentialAccount = async (credentialId: string) => {
     const program = initializeProgram();
-    if (!program || !publicKey) return null;
+    const credential = findCredential(credentialId);
+    if (!program || !publicKey || !credential) return null;
     try {
       const credentialPDA = deriveCredentialPDA(program, credentialId);
 
@@ -296,9 +424,15 @@ const Staking: React.FC = () => {
       }
 
       await program.methods
-        .initializeCredential(credentialId, new BN(STAKE_AMOUNT))
+        .initializeCredential(
+          CREDENTIAL_TYPES[credential.type as CredentialType],
+          DEFAULT_ROUND_PARAMS
+        )
         .accounts({
           credential: credentialPDA,
+          vault: deriveVaultPDA(program, credentialPDA),
+          targetCredential: getTargetCredential(credentialId),
+          config: deriveConfigPDA(program),
           authority: publicKey,
           systemProgram: web3.SystemProgram.programId,
         })
@@ -311,6 +445,19 @@ const Staking: React.FC = () => {
     }
   };
 
+  // Optional accounts are passed as the program id when absent.
+  const findOptionalAccount = async (
+    program: Program,
+    seeds: Buffer[]
+  ): Promise<web3.PublicKey> => {
+    const [address] = web3.PublicKey.findProgramAddressSync(
+      seeds,
+      program.programId
+    );
+    const info = await program.provider.connection.getAccountInfo(address);
+    return info ? address : program.programId;
+  };
+
   const confirmTransaction = async (signature: string) => {
     const connection = new Connection(RPC_ENDPOINTS.HELIUS);
     try {
@@ -340,14 +487,14 @@ const Staking: React.FC = () => {
 
     try {
       const credentialPDA = deriveCredentialPDA(program, credentialId);
-      const [verifierPDA] = web3.PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("verifier"),
-          credentialPDA.toBuffer(),
-          publicKey.toBuffer(),
-        ],
-        program.programId
-      );
+
+      // Initialize credential if it doesn't exist
+      if (!(await checkCredentialAccountExists(credentialPDA))) {
+        await initializeCredentialAccount(credentialId);
+      }
+
+      const { account, verifierPDA } = await fetchRound(program, credentialId);
+      if (!verifierPDA) return;
 
       // First, check if already staked
       try {
@@ -363,18 +510,29 @@ const Staking: React.FC = () => {
         // Account doesn't exist, continue with staking
       }
 
-      // Initialize credential if it doesn't exist
-      if (!(await checkCredentialAccountExists(credentialPDA))) {
-        await initializeCredentialAccount(credentialId);
-      }
+      const credentialType = findCredential(credentialId)!.type;
+      const poolMembership = await findOptionalAccount(program, [
+        Buffer.from("pool"),
+        Buffer.from([CREDENTIAL_TYPE_SEEDS[credentialType as CredentialType]]),
+        Buffer.from(account.poolTag),
+        publicKey.toBuffer(),
+      ]);
+      const allowlistEntry = await findOptionalAccount(program, [
+        Buffer.from("allowlist"),
+        publicKey.toBuffer(),
+      ]);
 
       let tx;
       try {
         tx = await program.methods
-          .stakeForCredential()
+          .stakeForCredential(account.minStake)
           .accounts({
             credential: credentialPDA,
+            vault: deriveVaultPDA(program, credentialPDA),
             verifier: verifierPDA,
+            allowlistEntry,
+            poolMembership,
+            reputation: deriveReputationPDA(program, publicKey),
             authority: publicKey,
             systemProgram: web3.SystemProgram.programId,
           })
@@ -482,18 +640,28 @@ const Staking: React.FC = () => {
     }));
 
     try {
-      const credentialPDA = deriveCredentialPDA(program, credentialId);
-      const [verifierPDA] = web3.PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("verifier"),
-          credentialPDA.toBuffer(),
-          publicKey.toBuffer(),
-        ],
-        program.programId
+      const { credentialPDA, account, verifierPDA } = await fetchRound(
+        program,
+        credentialId
+      );
+      if (!verifierPDA) return;
+
+      // Votes are committed as a hash first and revealed once every staker
+      // has committed, so keep the vote and salt until then.
+      const salt = crypto.getRandomValues(new Uint8Array(32));
+      const commitment = await voteCommitment(
+        credentialPDA,
+        publicKey,
+        isAuthentic,
+        salt
+      );
+      localStorage.setItem(
+        voteStorageKey(credentialPDA, account.roundId, publicKey),
+        JSON.stringify({ isAuthentic, salt: Array.from(salt) })
       );
 
       const tx = await program.methods
-        .makeDecision(isAuthentic)
+        .commitVote(commitment)
         .accounts({
           credential: credentialPDA,
           verifier: verifierPDA,
@@ -508,8 +676,8 @@ const Staking: React.FC = () => {
       // Verify if the vote was actually recorded
       try {
         const verifierInfo = await fetchVerifierInfo(credentialId);
-        if (verifierInfo?.hasVoted) {
-          toast.success("Successfully voted on credential");
+        if (verifierInfo?.hasCommitted) {
+          toast.success("Vote committed, reveal it once voting closes");
           // Update states immediately
           setVerifierStates((prev) => ({
             ...prev,
@@ -528,7 +696,7 @@ const Staking: React.FC = () => {
       if (status?.value?.err) {
         throw new Error("Transaction failed");
       } else {
-        toast.success("Successfully voted on credential");
+        toast.success("Vote committed, reveal it once voting closes");
       }
     } catch (error: any) {
       console.error("Voting error:", error);
@@ -540,8 +708,8 @@ const Staking: React.FC = () => {
       ) {
         // Verify if the vote was actually recorded
         const verifierInfo = await fetchVerifierInfo(credentialId);
-        if (verifierInfo?.hasVoted) {
-          toast.success("Vote was successfully recorded");
+        if (verifierInfo?.hasCommitted) {
+          toast.success("Vote was successfully committed");
           // Update states immediately
           setVerifierStates((prev) => ({
             ...prev,
@@ -560,54 +728,125 @@ const Staking: React.FC = () => {
     }
   };
 
-  const updateCredentialVerificationStatus = async (
-    credentialId: string,
-    credentialState: CredentialState
-  ) => {
-    const wallet = {
-      publicKey,
-      signTransaction,
-      signAllTransactions,
-    };
+  const handleReveal = async (credentialId: string) => {
+    const program = initializeProgram();
+    if (!program || !publicKey) {
+      toast.error("Please connect your wallet");
+      return;
+    }
 
-    const devnetConnection = new Connection(RPC_ENDPOINTS.HELIUS, {
-      commitment: "confirmed",
-      confirmTransactionInitialTimeout: 60000,
-    });
+    setStakingState((prev) => ({
+      ...prev,
+      [credentialId]: {
+        ...prev[credentialId],
+        isVoting: true,
+        stakingError: null,
+      },
+    }));
 
-    const provider = new AnchorProvider(devnetConnection, wallet as any, {
-      commitment: "confirmed",
-      preflightCommitment: "confirmed",
-      skipPreflight: false,
-    });
+    try {
+      const { credentialPDA, account, verifierPDA } = await fetchRound(
+        program,
+        credentialId
+      );
+      if (!verifierPDA) return;
 
-    const program = new Program(
-      IDL1 as any,
-      "AsjDSV316uhQKcGNfCECGBzj7eHwrYXho7CivhiQNJQ1",
-      provider
+      const stored = localStorage.getItem(
+        voteStorageKey(credentialPDA, account.roundId, publicKey)
+      );
+      if (!stored) {
+        toast.error("The committed vote was not found in this browser");
+        return;
+      }
+      const { isAuthentic, salt } = JSON.parse(stored);
+
+      await program.methods
+        .revealVote(isAuthentic, salt)
+        .accounts({
+          credential: credentialPDA,
+          verifier: verifierPDA,
+          authority: publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      const verifierInfo = await fetchVerifierInfo(credentialId);
+      if (verifierInfo) {
+        setVerifierStates((prev) => ({
+          ...prev,
+          [credentialId]: verifierInfo,
+        }));
+      }
+      toast.success("Successfully voted on credential");
+    } catch (error) {
+      console.error("Reveal error:", error);
+      toast.error("Failed to reveal vote");
+    } finally {
+      setStakingState((prev) => ({
+        ...prev,
+        [credentialId]: { ...prev[credentialId], isVoting: false },
+      }));
+    }
+  };
+
+  // Writes the outcome back to the tablu credential, which records the
+  // majority's verifiers, so their accounts are passed along.
+  const settleCredentialVerification = async (credentialId: string) => {
+    const program = initializeProgram();
+    if (!program) return;
+
+    const { credentialPDA, account } = await fetchRound(program, credentialId);
+    if (!account.isFinalized || account.isSettled || account.outcome.tie) {
+      return;
+    }
+
+    const majorityAuthentic = !!account.outcome.authentic;
+    const majority = (await getAllVerifiersForCredential(credentialId)).filter(
+      (verifier) =>
+        verifier.account.roundId.eq(account.roundId) &&
+        verifier.account.hasVoted &&
+        verifier.account.votedAuthentic === majorityAuthentic
     );
 
-    if (credentialState.isFinalized) {
-      const isVerified =
-        credentialState.authenticVotes > credentialState.verifierCount / 2;
+    const [targetProfile] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("profile"), account.targetOwner.toBuffer()],
+      TABLU_PROGRAM_ID
+    );
+    const [tabluConfig] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("config")],
+      TABLU_PROGRAM_ID
+    );
+    const [settlementAuthority] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("settlement_authority")],
+      program.programId
+    );
 
-      try {
-        await program.methods
-          .updateCredentialStatus(isVerified ? "Verified" : "Rejected")
-          .accounts({
-            credential: credentialId,
-            authority: publicKey,
-            systemProgram: web3.SystemProgram.programId,
-          })
-          .rpc();
+    try {
+      await program.methods
+        .settleVerification()
+        .accounts({
+          credential: credentialPDA,
+          targetCredential: account.target,
+          targetProfile,
+          tabluConfig,
+          settlementAuthority,
+          tabluProgram: TABLU_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          majority.map((verifier) => ({
+            pubkey: verifier.publicKey,
+            isWritable: false,
+            isSigner: false,
+          }))
+        )
+        .rpc();
 
-        // Remove from unverified credentials if successful
-        setUnverifiedCredentials((prev) =>
-          prev.filter((cred) => cred.id !== credentialId)
-        );
-      } catch (error) {
-        console.error("Failed to update credential status:", error);
-      }
+      // Remove from unverified credentials if successful
+      setUnverifiedCredentials((prev) =>
+        prev.filter((cred) => cred.id !== credentialId)
+      );
+    } catch (error) {
+      console.error("Failed to settle credential verification:", error);
     }
   };
 
@@ -617,7 +856,7 @@ const Staking: React.FC = () => {
 
     try {
       const credentialPDA = deriveCredentialPDA(program, credentialId);
-      const verifierAccounts = await program.account.verifier.all([
+      const verifierAccounts: any[] = await program.account.verifier.all([
         {
           memcmp: {
             offset: 8,
@@ -626,7 +865,7 @@ const Staking: React.FC = () => {
         },
       ]);
 
-      return verifierAccounts.map((account) => account.account);
+      return verifierAccounts;
     } catch (error) {
       console.error("Error fetching verifiers:", error);
       return [];
@@ -650,64 +889,66 @@ const Staking: React.FC = () => {
     }));
 
     try {
-      const credentialPDA = deriveCredentialPDA(program, credentialId);
-      const [verifierPDA] = web3.PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("verifier"),
-          credentialPDA.toBuffer(),
-          publicKey.toBuffer(),
-        ],
-        program.programId
-      );
-
-      const credentialStateFetched = await fetchCredentialState(credentialId);
+      const round = await fetchRound(program, credentialId);
+      const { credentialPDA, verifierPDA } = round;
+      if (!verifierPDA) return;
+      let account = round.account;
       const nowSeconds = Math.floor(Date.now() / 1000);
-      const fiveDaysInSecs = 5 * 24 * 60 * 60;
 
-      if (
-        credentialStateFetched &&
-        !credentialStateFetched.isFinalized &&
-        credentialStateFetched.verifierCount < 10 &&
-        nowSeconds - credentialStateFetched.createdAt >= fiveDaysInSecs
-      ) {
+      // Rounds that missed their quorum are cancelled and rounds whose
+      // reveal phase lapsed are finalized by whoever claims first.
+      if (!account.isFinalized && !account.isCancelled) {
+        if (
+          account.stakerCount < account.quorum &&
+          nowSeconds > account.stakingDeadline.toNumber()
+        ) {
+          await program.methods
+            .cancelCredential()
+            .accounts({ credential: credentialPDA })
+            .rpc();
+        } else if (
+          !account.votingDeadline.isZero() &&
+          nowSeconds > account.revealDeadline.toNumber()
+        ) {
+          await program.methods
+            .finalizeAfterDeadline()
+            .accounts({ credential: credentialPDA })
+            .rpc();
+        }
+        account = await program.account.credential.fetch(credentialPDA);
+      }
+
+      if (account.isCancelled) {
         await program.methods
-          .refundExpiredStakes()
+          .refundExpiredStakes(false)
           .accounts({
             credential: credentialPDA,
+            vault: deriveVaultPDA(program, credentialPDA),
             verifier: verifierPDA,
             authority: publicKey,
             systemProgram: web3.SystemProgram.programId,
           })
           .rpc();
         toast.success(
-          "Your stake has been refunded as the 5-day window has expired without consensus!"
+          "Your stake has been refunded as the round expired without consensus!"
         );
         return;
       }
 
       await program.methods
-        .claimReward()
+        .claimReward(false)
         .accounts({
           credential: credentialPDA,
+          vault: deriveVaultPDA(program, credentialPDA),
           verifier: verifierPDA,
+          reputation: deriveReputationPDA(program, publicKey),
           authority: publicKey,
           systemProgram: web3.SystemProgram.programId,
         })
         .rpc();
 
-      // After successful claim, check if all eligible stakers have claimed
-      const credentialState = await fetchCredentialState(credentialId);
-      if (credentialState && credentialState.isFinalized) {
-        const allVerifiers = await getAllVerifiersForCredential(credentialId);
-        const allClaimsMade = allVerifiers.every((v: any) => v.hasClaimed);
-
-        if (allClaimsMade) {
-          await updateCredentialVerificationStatus(
-            credentialId,
-            credentialState
-          );
-        }
-      }
+      // Settling is permissionless; the first claimant writes the outcome back
+      await settleCredentialVerification(credentialId);
 
       toast.success("Successfully claimed reward");
     } catch (error) {
@@ -721,6 +962,20 @@ const Staking: React.FC = () => {
     }
   };
 
+  const toCredentialState = (account: any): CredentialState => ({
+    stakerCount: account.stakerCount,
+    quorum: account.quorum,
+    commitCount: account.commitCount,
+    voteCount: account.voteCount,
+    authenticVotes: account.authenticVotes,
+    isFinalized: account.isFinalized,
+    isCancelled: account.isCancelled,
+    outcome: Object.keys(account.outcome)[0] as CredentialState["outcome"],
+    stakingDeadline: account.stakingDeadline.toNumber(),
+    votingDeadline: account.votingDeadline.toNumber(),
+    revealDeadline: account.revealDeadline.toNumber(),
+  });
+
   const fetchCredentialState = async (
     credentialId: string
   ): Promise<CredentialState | null> => {
@@ -730,14 +985,7 @@ const Staking: React.FC = () => {
     try {
       const credentialPDA = deriveCredentialPDA(program, credentialId);
       const account = await program.account.credential.fetch(credentialPDA);
-
-      // Include authenticVotes in the return object
-      return {
-        verifierCount: (account as any).verifierCount,
-        isFinalized: (account as any).isFinalized,
-        authenticVotes: (account as any).authenticVotes,
-        createdAt: (account as any).createdAt,
-      };
+      return toCredentialState(account);
     } catch {
       return null;
     }
@@ -747,15 +995,8 @@ const Staking: React.FC = () => {
     const program = initializeProgram();
     if (!program || !publicKey) return false;
     try {
-      const credentialPDA = deriveCredentialPDA(program, credentialId);
-      const [verifierPDA] = web3.PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("verifier"),
-          credentialPDA.toBuffer(),
-          publicKey.toBuffer(),
-        ],
-        program.programId
-      );
+      const { verifierPDA } = await fetchRound(program, credentialId);
+      if (!verifierPDA) return false;
       await program.account.verifier.fetch(verifierPDA);
       return true;
     } catch {
@@ -763,23 +1004,19 @@ const Staking: React.FC = () => {
     }
   };
 
-  const fetchVerifierInfo = async (credentialId: string) => {
+  const fetchVerifierInfo = async (
+    credentialId: string
+  ): Promise<VerifierInfo | null> => {
     const program = initializeProgram();
     if (!program || !publicKey) return null;
 
     try {
-      const credentialPDA = deriveCredentialPDA(program, credentialId);
-      const [verifierPDA] = web3.PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("verifier"),
-          credentialPDA.toBuffer(),
-          publicKey.toBuffer(),
-        ],
-        program.programId
-      );
+      const { verifierPDA } = await fetchRound(program, credentialId);
+      if (!verifierPDA) return null;
       const account = await program.account.verifier.fetch(verifierPDA);
 
       return {
+        hasCommitted: (account as any).hasCommitted,
         hasVoted: (account as any).hasVoted,
         hasClaimed: (account as any).hasClaimed,
         votedAuthentic: (account as any).votedAuthentic,
@@ -849,22 +1086,21 @@ const Staking: React.FC = () => {
         return;
       }
 
+      // Rounds are keyed by the tablu credential they verify
+      const credIdForRound = (account: any) =>
+        unverifiedCredentials.find(
+          (cred) => cred.publicKey === account.target.toBase58()
+        )?.id;
+
       // Try to fetch credential state
       try {
         const account = await program.account.credential.fetch(pubKey);
-        const credId = await program.account.credential
-          .fetch(pubKey)
-          .then((acc: any) => acc.id);
+        const credId = credIdForRound(account);
 
         if (credId) {
           setCredentialStates((prev) => ({
             ...prev,
-            [credId]: {
-              verifierCount: (account as any).verifierCount,
-              isFinalized: (account as any).isFinalized,
-              authenticVotes: (account as any).authenticVotes,
-              createdAt: (account as any).createdAt,
-            },
+            [credId]: toCredentialState(account),
           }));
 
           // Update verifier state for this credential
@@ -883,11 +1119,10 @@ const Staking: React.FC = () => {
           if (verifierAccount) {
             const credentialPDA = (verifierAccount as any).credential;
             if (credentialPDA) {
-              // Fetch the credential ID from the PDA
               const credAccount = await program.account.credential.fetch(
                 credentialPDA
               );
-              const credId = (credAccount as any).id;
+              const credId = credIdForRound(credAccount);
 
               if (credId) {
                 const verifierInfo = await fetchVerifierInfo(credId);
@@ -936,7 +1171,7 @@ const Staking: React.FC = () => {
         if (!program) return;
 
         // Subscribe to each credential's account changes
-        visibleCredentials.forEach((cred) => {
+        visibleCredentials.forEach(async (cred) => {
           const credentialPDA = deriveCredentialPDA(program, cred.id);
           const subscribeRequest = {
             jsonrpc: "2.0",
@@ -951,14 +1186,10 @@ const Staking: React.FC = () => {
 
           // Also subscribe to associated verifier accounts
           if (publicKey) {
-            const [verifierPDA] = web3.PublicKey.findProgramAddressSync(
-              [
-                Buffer.from("verifier"),
-                credentialPDA.toBuffer(),
-                publicKey.toBuffer(),
-              ],
-              program.programId
+            const { verifierPDA } = await fetchRound(program, cred.id).catch(
+              () => ({ verifierPDA: null })
             );
+            if (!verifierPDA || ws.readyState !== WebSocket.OPEN) return;
             const verifierRequest = {
               jsonrpc: "2.0",
               id: Date.now() + 1,
@@ -1063,21 +1294,43 @@ const Staking: React.FC = () => {
   ): boolean {
     if (!credentialState || !verifier || !verifier.hasVoted) return false;
 
-    const authenticVotes = credentialState.authenticVotes;
-    const totalVotes = credentialState.verifierCount;
-    const inauthenticVotes = totalVotes - authenticVotes;
-
-    // User voted authentic and authentic votes are majority
-    if (verifier.votedAuthentic && authenticVotes > inauthenticVotes) {
-      return true;
+    // The program weighs votes and applies the round's majority threshold,
+    // so go by the outcome it recorded rather than recounting here.
+    switch (credentialState.outcome) {
+      case "tie":
+        return true;
+      case "authentic":
+        return verifier.votedAuthentic;
+      case "notAuthentic":
+        return !verifier.votedAuthentic;
+      default:
+        return false;
     }
+  }
 
-    // User voted inauthentic and inauthentic votes are majority
-    if (!verifier.votedAuthentic && inauthenticVotes > authenticVotes) {
+  // Claiming also refunds rounds that missed their quorum and finalizes
+  // rounds whose reveal phase lapsed, so those are claimable before the
+  // outcome is known.
+  function canCloseRound(
+    credentialState: CredentialState,
+    verifier: VerifierInfo
+  ): boolean {
+    if (!credentialState || !verifier || credentialState.isFinalized) {
+      return false;
+    }
+    const nowSeconds = Math.floor(Date.now() / 1000);
+    if (credentialState.isCancelled) return true;
+    if (
+      credentialState.stakerCount < credentialState.quorum &&
+      nowSeconds > credentialState.stakingDeadline
+    ) {
       return true;
     }
-
-    return false;
+    return (
+      verifier.hasVoted &&
+      credentialState.votingDeadline !== 0 &&
+      nowSeconds > credentialState.revealDeadline
+    );
   }
 
   const getRewardClaimableReason = (
@@ -1087,22 +1340,22 @@ const Staking: React.FC = () => {
   ): string => {
     if (!credentialState) return "Staking not started";
     if (!verifierInfo) return "Must stake before claiming reward";
-    if (!verifierInfo.hasVoted) return "Must vote before claiming reward";
     if (verifierInfo.hasClaimed) return "Reward already claimed";
+    if (canCloseRound(credentialState, verifierInfo)) {
+      return credentialState.isCancelled ||
+        credentialState.stakerCount < credentialState.quorum
+        ? "Round expired, your stake can be refunded"
+        : "Reveal period ended, claiming finalizes the round";
+    }
+    if (!verifierInfo.hasCommitted) return "Must vote before claiming reward";
+    if (!verifierInfo.hasVoted) return "Must reveal your vote before claiming";
     if (!credentialState.isFinalized) return "Voting period not finished";
 
-    const authenticVotes = credentialState.authenticVotes;
-    const totalVotes = credentialState.verifierCount;
-    const inauthenticVotes = totalVotes - authenticVotes;
-
-    if (verifierInfo.votedAuthentic && authenticVotes <= inauthenticVotes) {
-      return "Your vote is in the minority";
-    }
-    if (!verifierInfo.votedAuthentic && inauthenticVotes <= authenticVotes) {
+    if (!isUserInMajority(credentialState, verifierInfo)) {
       return "Your vote is in the minority";
     }
 
-    return "Unknown reason";
+    return "Reward can be claimed";
   };
 
   const handleViewProof = async (credentialId: string) => {
@@ -1190,16 +1443,20 @@ const Staking: React.FC = () => {
         {getFilteredCredentials().length > 0 ? (
           getFilteredCredentials().map((credential, index) => {
             const alreadyVoted = verifierStates[credential.id]?.hasVoted;
+            const awaitingReveal =
+              verifierStates[credential.id]?.hasCommitted && !alreadyVoted;
 
             const isStaked = stakedCredentials.has(credential.id);
             const credentialState = credentialStates[credential.id];
             const isConsensusMet =
-              credentialState?.verifierCount >= 10 ||
+              credentialState?.stakerCount >= credentialState?.quorum ||
               credentialState?.isFinalized;
 
             const state = credentialStates[credential.id];
             const verifierInfo = verifierStates[credential.id];
-            const canClaimReward = isUserInMajority(state, verifierInfo);
+            const canClaimReward =
+              isUserInMajority(state, verifierInfo) ||
+              canCloseRound(state, verifierInfo);
             const rewardReason = getRewardClaimableReason(
               credential.id,
               state,
@@ -1252,28 +1509,40 @@ const Staking: React.FC = () => {
                       : "Stake"}
                   </button>
                   <div className="voting-buttons">
-                    <button
-                      className="vote-authentic"
-                      onClick={() => handleVote(credential.id, true)}
-                      disabled={
-                        !canVote(credential.id) ||
-                        alreadyVoted ||
-                        stakingState[credential.id]?.isVoting
-                      }
-                    >
-                      {alreadyVoted ? "Voted" : "IsTrue"}
-                    </button>
-                    <button
-                      className="vote-inauthentic"
-                      onClick={() => handleVote(credential.id, false)}
-                      disabled={
-                        !canVote(credential.id) ||
-                        alreadyVoted ||
-                        stakingState[credential.id]?.isVoting
-                      }
-                    >
-                      {alreadyVoted ? "Voted" : "IsFalse"}
-                    </button>
+                    {awaitingReveal ? (
+                      <button
+                        className="vote-authentic"
+                        onClick={() => handleReveal(credential.id)}
+                        disabled={stakingState[credential.id]?.isVoting}
+                      >
+                        Reveal Vote
+                      </button>
+                    ) : (
+                      <>
+                        <button
+                          className="vote-authentic"
+                          onClick={() => handleVote(credential.id, true)}
+                          disabled={
+                            !canVote(credential.id) ||
+                            alreadyVoted ||
+                            stakingState[credential.id]?.isVoting
+                          }
+                        >
+                          {alreadyVoted ? "Voted" : "IsTrue"}
+                        </button>
+                        <button
+                          className="vote-inauthentic"
+                          onClick={() => handleVote(credential.id, false)}
+                          disabled={
+                            !canVote(credential.id) ||
+                            alreadyVoted ||
+                            stakingState[credential.id]?.isVoting
+                          }
+                        >
+                          {alreadyVoted ? "Voted" : "IsFalse"}
+                        </button>
+                      </>
+                    )}
                   </div>
                   <div className="claim-button-wrapper">
                     <button
@@ -1281,11 +1550,7 @@ const Staking: React.FC = () => {
                         verifierInfo?.hasClaimed ? "claimed" : ""
                       }`}
                       onClick={() => handleClaim(credential.id)}
-                      disabled={
-                        !canClaimReward ||
-                        verifierInfo?.hasClaimed ||
-                        !credentialState.isFinalized
-                      }
+                      disabled={!canClaimReward || verifierInfo?.hasClaimed}
                     >
                       {verifierInfo?.hasClaimed
                         ? "Reward Claimed"
@@ -5,6 +5,7 @@ import { notification } from "antd";
 import { Connection, PublicKey } from "@solana/web3.js";
 import { IDL } from "./uploadidl";
 import CredentialFormBase from "./CredentialFormBase";
+import { getSubmissionAccounts, PAY_WITH_SOL } from "./submissionAccounts";
 import { saveCredentialUpload } from "../../../../server/MongoDB/utils/saveCredential";
 import { generateStableCredentialId } from "../../../utils/generateStableIDS";
 
@@ -88,20 +89,27 @@ const DegreeForm: React.FC = () => {
       }
 
       const program = getProgram();
-      const credentialAccount = web3.Keypair.generate();
-      const treasuryWallet = new web3.PublicKey(
-        "C9KvY6JP9LNJo7vpJhkzVdtAVn6pLKuB52uhfLWCj4oU"
+      const { profile, credential, fee } = await getSubmissionAccounts(
+        program,
+        publicKey,
+        "degree"
       );
 
       await program.methods
-        .submitDegree(degreeName, collegeName, new BN(parseInt(passoutYear)))
+        .submitDegree(
+          degreeName,
+          collegeName,
+          new BN(parseInt(passoutYear)),
+          null,
+          PAY_WITH_SOL
+        )
         .accounts({
-          credential: credentialAccount.publicKey,
+          profile,
+          credential,
           user: publicKey,
-          treasury: treasuryWallet,
+          fee,
           systemProgram: web3.SystemProgram.programId,
         })
-        .signers([credentialAccount])
         .rpc();
 
       notification.success({
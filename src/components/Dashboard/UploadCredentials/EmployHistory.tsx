@@ -1,5 +1,6 @@
 import React, { useState } from "react";
 import CredentialFormBase from "./CredentialFormBase";
+import { getSubmissionAccounts, PAY_WITH_SOL } from "./submissionAccounts";
 import { useWallet } from "@solana/wallet-adapter-react";
 import { Program, AnchorProvider, web3, BN } from "@project-serum/anchor";
 import { notification } from "antd";
@@ -87,9 +88,10 @@ const EmploymentHistoryForm: React.FC = () => {
 
       // Proceed with blockchain transaction
       const program = getProgram();
-      const credentialAccount = web3.Keypair.generate();
-      const treasuryWallet = new web3.PublicKey(
-        "C9KvY6JP9LNJo7vpJhkzVdtAVn6pLKuB52uhfLWCj4oU"
+      const { profile, credential, fee } = await getSubmissionAccounts(
+        program,
+        publicKey,
+        "employment"
       );
 
       const startTimestamp = new Date(startDate).getTime() / 1000;
@@ -101,19 +103,23 @@ const EmploymentHistoryForm: React.FC = () => {
 
       await program.methods
         .submitEmployment(
-          companyName,
-          jobTitle,
-          new BN(startTimestamp),
-          endTimestamp ? new BN(endTimestamp) : null,
-          currentlyWorking
+          {
+            companyName,
+            jobTitle,
+            startDate: new BN(startTimestamp),
+            endDate: endTimestamp ? new BN(endTimestamp) : null,
+            currentlyWorking,
+            expiresAt: null,
+          },
+          PAY_WITH_SOL
         )
         .accounts({
-          employment: credentialAccount.publicKey,
+          profile,
+          employment: credential,
           user: publicKey,
-          treasury: treasuryWallet,
+          fee,
           systemProgram: web3.SystemProgram.programId,
         })
-        .signers([credentialAccount])
         .rpc();
 
       notification.success({
@@ -6,6 +6,7 @@ import { Connection, PublicKey } from "@solana/web3.js";
 import BN from "bn.js";
 import { IDL } from "./uploadidl";
 import CredentialFormBase from "./CredentialFormBase";
+import { getSubmissionAccounts, PAY_WITH_SOL } from "./submissionAccounts";
 import { saveCredentialUpload } from "../../../../server/MongoDB/utils/saveCredential";
 import { generateStableCredentialId } from "../../../utils/generateStableIDS";
 
@@ -115,9 +116,10 @@ const OnlineCertificationsForm: React.FC = () => {
 
       // Proceed with blockchain transaction
       const program = getProgram();
-      const certificateKeypair = web3.Keypair.generate();
-      const treasuryWallet = new web3.PublicKey(
-        "C9KvY6JP9LNJo7vpJhkzVdtAVn6pLKuB52uhfLWCj4oU"
+      const { profile, credential, fee } = await getSubmissionAccounts(
+        program,
+        publicKey,
+        "certificate"
       );
 
       const issueTimestamp = Math.floor(issueDate.getTime() / 1000);
@@ -127,15 +129,17 @@ const OnlineCertificationsForm: React.FC = () => {
           certificationName,
           issuer,
           new BN(issueTimestamp),
-          proofLink ? proofLink : null
+          proofLink ? proofLink : null,
+          null,
+          PAY_WITH_SOL
         )
         .accounts({
-          certificate: certificateKeypair.publicKey,
+          profile,
+          certificate: credential,
           user: publicKey,
-          treasury: treasuryWallet,
+          fee,
           systemProgram: web3.SystemProgram.programId,
         })
-        .signers([certificateKeypair])
         .rpc();
 
       notification.success({
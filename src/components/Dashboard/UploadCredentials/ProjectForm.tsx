@@ -1,7 +1,8 @@
 import React, { useState } from "react";
 import CredentialFormBase from "./CredentialFormBase";
+import { getSubmissionAccounts, PAY_WITH_SOL } from "./submissionAccounts";
 import { useWallet } from "@solana/wallet-adapter-react";
-import { Program, AnchorProvider, web3, BN } from "@project-serum/anchor";
+import { Program, AnchorProvider, web3 } from "@project-serum/anchor";
 import { notification } from "antd";
 import { Connection, PublicKey } from "@solana/web3.js";
 import { IDL } from "./uploadidl";
@@ -100,16 +101,18 @@ const ProjectForm: React.FC = () => {
 
       // Proceed with blockchain transaction
       const program = getProgram();
-      const credentialAccount = web3.Keypair.generate();
-      const treasuryWallet = new web3.PublicKey(
-        "C9KvY6JP9LNJo7vpJhkzVdtAVn6pLKuB52uhfLWCj4oU"
+      const { profile, credential, fee } = await getSubmissionAccounts(
+        program,
+        publicKey,
+        "project"
       );
 
-      const startTimestamp = new Date(startDate).getTime() / 1000;
+      // Project dates are stored as i32 seconds, which take plain numbers
+      const startTimestamp = Math.floor(new Date(startDate).getTime() / 1000);
       const endTimestamp = currentlyWorking
         ? null
         : endDate
-        ? new Date(endDate).getTime() / 1000
+        ? Math.floor(new Date(endDate).getTime() / 1000)
         : null;
 
       const collaboratorsArray = collaborators.trim()
@@ -118,21 +121,25 @@ const ProjectForm: React.FC = () => {
 
       await program.methods
         .submitProject(
-          projectName,
-          projectDetails,
-          collaboratorsArray,
-          new BN(startTimestamp),
-          endTimestamp ? new BN(endTimestamp) : null,
-          currentlyWorking,
-          link
+          {
+            projectName,
+            projectDescription: projectDetails,
+            collaborators: collaboratorsArray,
+            startDate: startTimestamp,
+            endDate: endTimestamp,
+            currentlyWorking,
+            projectLink: link,
+            expiresAt: null,
+          },
+          PAY_WITH_SOL
         )
         .accounts({
-          project: credentialAccount.publicKey,
+          profile,
+          project: credential,
           user: publicKey,
-          treasury: treasuryWallet,
+          fee,
           systemProgram: web3.SystemProgram.programId,
         })
-        .signers([credentialAccount])
         .rpc();
 
       notification.success({
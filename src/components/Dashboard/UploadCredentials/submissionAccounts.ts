@@ -0,0 +1,55 @@
+import { Program, web3 } from "@project-serum/anchor";
+
+export type SubmissionKind =
+  | "degree"
+  | "project"
+  | "skill"
+  | "employment"
+  | "certificate";
+
+// Submission fees are paid in SOL from these forms.
+export const PAY_WITH_SOL = { sol: {} };
+
+// Credentials live at `[kind, user, index]`, where the index is the user's
+// running count of that kind kept on their profile, and every submission
+// pays its fee to the treasury recorded in the fee config.
+export const getSubmissionAccounts = async (
+  program: Program,
+  user: web3.PublicKey,
+  kind: SubmissionKind
+) => {
+  const [profile] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("profile"), user.toBuffer()],
+    program.programId
+  );
+  const existingProfile: any = await program.account.userProfile
+    .fetch(profile)
+    .catch(() => null);
+  const count: number = existingProfile ? existingProfile[`${kind}Count`] : 0;
+
+  const index = Buffer.alloc(4);
+  index.writeUInt32LE(count);
+  const [credential] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from(kind), user.toBuffer(), index],
+    program.programId
+  );
+
+  const [feeConfig] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("fee_config")],
+    program.programId
+  );
+  const { treasury }: any = await program.account.feeConfig.fetch(feeConfig);
+
+  return {
+    profile,
+    credential,
+    fee: {
+      feeConfig,
+      treasury,
+      tokenMint: program.programId,
+      userTokenAccount: program.programId,
+      treasuryTokenAccount: program.programId,
+      tokenProgram: program.programId,
+    },
+  };
+};
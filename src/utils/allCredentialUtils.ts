@@ -6,17 +6,11 @@ import { generateStableCredentialId } from "./generateStableIDS";
 
 const programId = new PublicKey("apwW9Vqxtu4Ga2dQ4R91jyYtWZ9HUFtx13MmPPfwLEb");
 
-const accountSizes = {
-  degree: 313, // 8 + 32 + 128 + 128 + 8 + 1 + 8
-  employment: 578, // 8 + 32 + 128 + 128 + 8 + 8 + 1 + 8 + 1 + 256
-  project: 1602, // 8 + 32 + 256 + 512 + 256 + 8 + 8 + 1 + 256 + 8 + 1 + 256
-  certificate: 825, // 8 + 32 + 128 + 128 + 8 + 256 + 8 + 1 + 256
-  skill: 690, // 8 + 32 + 128 + 1 + 256 + 8 + 1 + 256
-};
-
 const getStatusString = (status: any) => {
   if (status?.verified) return "Verified";
   if (status?.rejected) return "Rejected";
+  if (status?.revoked) return "Revoked";
+  if (status?.expired) return "Expired";
   if (status?.pending) return "Pending";
   return "Pending"; // fallback
 };
@@ -46,7 +40,7 @@ export const fetchUnverifiedCredentials = async (
       {
         filters: [
           {
-            dataSize: accountSizes.degree,
+            memcmp: program.coder.accounts.memcmp("UserDegreeCredential"),
           },
         ],
       }
@@ -60,9 +54,10 @@ export const fetchUnverifiedCredentials = async (
             account.account.data
           );
 
-          if (!decoded.status.verified && !decoded.status.rejected) {
+          if (decoded.status.pending) {
             return {
               type: "Degree",
+              publicKey: account.pubkey.toBase58(),
               title: decoded.degreeName,
               dateIssued: new Date(
                 decoded.timestamp.toNumber() * 1000
@@ -92,7 +87,7 @@ export const fetchUnverifiedCredentials = async (
       {
         filters: [
           {
-            dataSize: accountSizes.employment,
+            memcmp: program.coder.accounts.memcmp("EmploymentCredential"),
           },
         ],
       }
@@ -106,7 +101,7 @@ export const fetchUnverifiedCredentials = async (
             account.account.data
           );
 
-          if (!decoded.status.verified && !decoded.status.rejected) {
+          if (decoded.status.pending) {
             const startDate = new Date(
               decoded.startDate.toNumber() * 1000
             ).toLocaleDateString();
@@ -120,6 +115,7 @@ export const fetchUnverifiedCredentials = async (
 
             return {
               type: "Employment History",
+              publicKey: account.pubkey.toBase58(),
               title: decoded.jobTitle,
               dateIssued: `${startDate} - ${endDateStr}`,
               status: getStatusString(decoded.status),
@@ -147,7 +143,7 @@ export const fetchUnverifiedCredentials = async (
       {
         filters: [
           {
-            dataSize: accountSizes.project,
+            memcmp: program.coder.accounts.memcmp("ProjectCredential"),
           },
         ],
       }
@@ -161,7 +157,7 @@ export const fetchUnverifiedCredentials = async (
             account.account.data
           );
 
-          if (!decoded.status.verified && !decoded.status.rejected) {
+          if (decoded.status.pending) {
             const startDate = safeParseTimestamp(decoded.startDate);
             let endDateStr = "Present";
 
@@ -170,6 +166,7 @@ export const fetchUnverifiedCredentials = async (
             }
             return {
               type: "Project",
+              publicKey: account.pubkey.toBase58(),
               title: decoded.projectName,
               dateIssued: `${startDate} - ${endDateStr}`,
               status: getStatusString(decoded.status),
@@ -202,7 +199,7 @@ export const fetchUnverifiedCredentials = async (
       {
         filters: [
           {
-            dataSize: accountSizes.certificate,
+            memcmp: program.coder.accounts.memcmp("CertificateCredential"),
           },
         ],
       }
@@ -216,9 +213,10 @@ export const fetchUnverifiedCredentials = async (
             account.account.data
           );
 
-          if (!decoded.status.verified && !decoded.status.rejected) {
+          if (decoded.status.pending) {
             return {
               type: "Certificate",
+              publicKey: account.pubkey.toBase58(),
               title: decoded.certificationName,
               dateIssued: new Date(
                 decoded.dateOfIssue.toNumber() * 1000
@@ -249,7 +247,7 @@ export const fetchUnverifiedCredentials = async (
       {
         filters: [
           {
-            dataSize: accountSizes.skill,
+            memcmp: program.coder.accounts.memcmp("SkillCredential"),
           },
         ],
       }
@@ -263,9 +261,10 @@ export const fetchUnverifiedCredentials = async (
             account.account.data
           );
 
-          if (!decoded.status.verified && !decoded.status.rejected) {
+          if (decoded.status.pending) {
             return {
               type: "Skill",
+              publicKey: account.pubkey.toBase58(),
               title: decoded.skillName,
               dateIssued: new Date(
                 decoded.timestamp.toNumber() * 1000
@@ -295,7 +294,6 @@ export const fetchUnverifiedCredentials = async (
     return {
       ...cred,
       id: stableId,
-      publicKey: stableId,
     };
   });
 
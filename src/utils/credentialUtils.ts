@@ -31,6 +31,8 @@ const getNextEndpoint = () => {
 const getStatusString = (status: any) => {
   if (status?.verified) return "Verified";
   if (status?.rejected) return "Rejected";
+  if (status?.revoked) return "Revoked";
+  if (status?.expired) return "Expired";
   return "Pending";
 };
 